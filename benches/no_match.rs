@@ -49,6 +49,118 @@ fn bench_no_match(c: &mut Criterion) {
         b.iter(|| h.apply(black_box(LOG_LINE)));
     });
 
+    group.bench_function("hostname", |b| {
+        let h = Highlighter::builder()
+            .with_hostname_highlighter(HostnameConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("mac_address", |b| {
+        let h = Highlighter::builder()
+            .with_mac_address_highlighter(MacAddressConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("python_traceback", |b| {
+        let h = Highlighter::builder()
+            .with_python_traceback_highlighter(PythonTracebackConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("go_panic", |b| {
+        let h = Highlighter::builder()
+            .with_go_panic_highlighter(GoPanicConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("rust_backtrace", |b| {
+        let h = Highlighter::builder()
+            .with_rust_backtrace_highlighter(RustBacktraceConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("js_dotnet_stack", |b| {
+        let h = Highlighter::builder()
+            .with_js_dotnet_stack_trace_highlighter(JsDotnetStackTraceConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("windows_path", |b| {
+        let h = Highlighter::builder()
+            .with_windows_path_highlighter(WindowsPathConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("xml", |b| {
+        let h = Highlighter::builder()
+            .with_xml_highlighter(XmlConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("sql", |b| {
+        let h = Highlighter::builder()
+            .with_sql_highlighter(SqlConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("diff", |b| {
+        let h = Highlighter::builder()
+            .with_diff_highlighter(DiffConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("cloud_resource", |b| {
+        let h = Highlighter::builder()
+            .with_cloud_resource_highlighter(CloudResourceConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("placeholder", |b| {
+        let h = Highlighter::builder()
+            .with_placeholder_highlighter(PlaceholderConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("token", |b| {
+        let h = Highlighter::builder()
+            .with_token_highlighter(TokenConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("trace_id", |b| {
+        let h = Highlighter::builder()
+            .with_trace_id_highlighter(TraceIdConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
     group.bench_function("jvm_stack", |b| {
         let h = Highlighter::builder()
             .with_jvm_stack_trace_highlighter(JvmStackTraceConfig::default())
@@ -57,6 +169,14 @@ fn bench_no_match(c: &mut Criterion) {
         b.iter(|| h.apply(black_box(LOG_LINE)));
     });
 
+    group.bench_function("syslog", |b| {
+        let h = Highlighter::builder()
+            .with_syslog_highlighter(SyslogConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
     group.bench_function("email", |b| {
         let h = Highlighter::builder()
             .with_email_highlighter(EmailConfig::default())
@@ -73,6 +193,14 @@ fn bench_no_match(c: &mut Criterion) {
         b.iter(|| h.apply(black_box(LOG_LINE)));
     });
 
+    group.bench_function("hash", |b| {
+        let h = Highlighter::builder()
+            .with_hash_highlighter(HashConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
     group.bench_function("pointer", |b| {
         let h = Highlighter::builder()
             .with_pointer_highlighter(PointerConfig::default())
@@ -113,6 +241,30 @@ fn bench_no_match(c: &mut Criterion) {
         b.iter(|| h.apply(black_box(LOG_LINE)));
     });
 
+    group.bench_function("epoch", |b| {
+        let h = Highlighter::builder()
+            .with_epoch_highlighter(EpochConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("permission", |b| {
+        let h = Highlighter::builder()
+            .with_permission_highlighter(PermissionConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
+    group.bench_function("size", |b| {
+        let h = Highlighter::builder()
+            .with_size_highlighter(SizeConfig::default())
+            .build()
+            .unwrap();
+        b.iter(|| h.apply(black_box(LOG_LINE)));
+    });
+
     group.bench_function("duration", |b| {
         let h = Highlighter::builder()
             .with_duration_highlighter(DurationConfig::default())
@@ -48,15 +48,15 @@ _tspin() {
                     return 0
                     ;;
                 --enable)
-                    COMPREPLY=($(compgen -W "numbers urls emails pointers dates durations paths quotes key-value-pairs uuids ipv4 processes json keywords" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "numbers urls emails pointers dates durations paths quotes key-value-pairs uuids ipv4 processes json syslog hashes sizes epochs permissions keywords" -- "${cur}"))
                     return 0
                     ;;
                 --disable)
-                    COMPREPLY=($(compgen -W "numbers urls emails pointers dates durations paths quotes key-value-pairs uuids ipv4 processes json keywords" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "numbers urls emails pointers dates durations paths quotes key-value-pairs uuids ipv4 processes json syslog hashes sizes epochs permissions keywords" -- "${cur}"))
                     return 0
                     ;;
                 --extras)
-                    COMPREPLY=($(compgen -W "ipv6 jvm-stack-trace" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "cloud-resources diff go-panic hostnames ipv6 js-dotnet-stack-trace jvm-stack-trace mac-address placeholders python-traceback rust-backtrace sql tokens trace-ids windows-path xml" -- "${cur}"))
                     return 0
                     ;;
                 --pager)
@@ -16,6 +16,11 @@ uuids\t''
 ipv4\t''
 processes\t''
 json\t''
+syslog\t''
+hashes\t''
+sizes\t''
+epochs\t''
+permissions\t''
 keywords\t''"
 complete -c tspin -l disable -d 'Disable specific highlighters' -r -f -a "numbers\t''
 urls\t''
@@ -30,9 +35,28 @@ uuids\t''
 ipv4\t''
 processes\t''
 json\t''
+syslog\t''
+hashes\t''
+sizes\t''
+epochs\t''
+permissions\t''
 keywords\t''"
-complete -c tspin -l extras -d 'Enable extra highlighters (e.g., --extras ipv6)' -r -f -a "ipv6\t''
-jvm-stack-trace\t''"
+complete -c tspin -l extras -d 'Enable extra highlighters (e.g., --extras ipv6)' -r -f -a "cloud-resources\t''
+diff\t''
+go-panic\t''
+hostnames\t''
+ipv6\t''
+js-dotnet-stack-trace\t''
+jvm-stack-trace\t''
+mac-address\t''
+placeholders\t''
+python-traceback\t''
+rust-backtrace\t''
+sql\t''
+tokens\t''
+trace-ids\t''
+windows-path\t''
+xml\t''"
 complete -c tspin -l pager -d 'Override the default pager command used by tspin. (e.g. `--pager="ov -f [FILE]"`)' -r
 complete -c tspin -l completions -d 'Print shell completions to stdout' -r -f -a "bash\t''
 elvish\t''
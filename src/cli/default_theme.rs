@@ -17,19 +17,38 @@ pub fn default_theme_toml() -> String {
         regexes: _,
         numbers,
         uuids,
+        hashes,
         quotes,
         ipv4,
         ipv6,
+        mac_addresses,
         dates,
         durations,
+        sizes,
+        epochs,
+        permissions,
         paths,
         urls,
         emails,
+        hostnames,
         pointers,
         processes,
+        syslog,
         key_value_pairs,
         json,
         jvm_stack_traces,
+        python_tracebacks,
+        go_panics,
+        rust_backtraces,
+        js_dotnet_stack_traces,
+        windows_paths,
+        xml,
+        sql,
+        diff,
+        cloud_resources,
+        placeholders,
+        tokens,
+        trace_ids,
     } = Theme::default();
 
     let mut out = String::from(
@@ -48,19 +67,38 @@ pub fn default_theme_toml() -> String {
 
     push_config(&mut out, "numbers", &numbers);
     push_config(&mut out, "uuids", &uuids);
+    push_config(&mut out, "hashes", &hashes);
     push_quotes(&mut out, quotes);
     push_config(&mut out, "ipv4", &ipv4);
     push_config(&mut out, "ipv6", &ipv6);
+    push_config(&mut out, "mac_addresses", &mac_addresses);
     push_config(&mut out, "dates", &dates);
     push_config(&mut out, "durations", &durations);
+    push_config(&mut out, "sizes", &sizes);
+    push_epochs(&mut out, epochs);
+    push_config(&mut out, "permissions", &permissions);
     push_config(&mut out, "paths", &paths);
     push_config(&mut out, "urls", &urls);
     push_config(&mut out, "emails", &emails);
+    push_hostnames(&mut out, &hostnames);
     push_config(&mut out, "pointers", &pointers);
     push_config(&mut out, "processes", &processes);
-    push_config(&mut out, "key_value_pairs", &key_value_pairs);
-    push_config(&mut out, "json", &json);
+    push_config(&mut out, "syslog", &syslog);
+    push_key_value_pairs(&mut out, &key_value_pairs);
+    push_json(&mut out, &json);
     push_config(&mut out, "jvm_stack_traces", &jvm_stack_traces);
+    push_config(&mut out, "python_tracebacks", &python_tracebacks);
+    push_config(&mut out, "go_panics", &go_panics);
+    push_config(&mut out, "rust_backtraces", &rust_backtraces);
+    push_config(&mut out, "js_dotnet_stack_traces", &js_dotnet_stack_traces);
+    push_config(&mut out, "windows_paths", &windows_paths);
+    push_config(&mut out, "xml", &xml);
+    push_config(&mut out, "sql", &sql);
+    push_config(&mut out, "diff", &diff);
+    push_config(&mut out, "cloud_resources", &cloud_resources);
+    push_config(&mut out, "placeholders", &placeholders);
+    push_tokens(&mut out, tokens);
+    push_trace_ids(&mut out, &trace_ids);
 
     out
 }
@@ -94,6 +132,103 @@ fn push_quotes(out: &mut String, config: QuoteConfig) {
     writeln!(out, "style = {}", inline_style(config.style)).unwrap();
 }
 
+/// `[epochs]` carries its matching switches next to the styles.
+fn push_epochs(out: &mut String, config: EpochConfig) {
+    push_table(
+        out,
+        "epochs",
+        &[("style", config.style), ("annotation", config.annotation)],
+    );
+    writeln!(out, "key_value = {}", config.key_value).unwrap();
+    writeln!(out, "json = {}", config.json).unwrap();
+    writeln!(out, "anywhere = {}", config.anywhere).unwrap();
+    writeln!(out, "annotate = {}", config.annotate).unwrap();
+}
+
+/// `[tokens]` carries its length thresholds and collapse switch next to the
+/// styles.
+fn push_tokens(out: &mut String, config: TokenConfig) {
+    push_table(
+        out,
+        "tokens",
+        &[
+            ("scheme", config.scheme),
+            ("header", config.header),
+            ("payload", config.payload),
+            ("signature", config.signature),
+            ("blob", config.blob),
+            ("separator", config.separator),
+            ("collapsed", config.collapsed),
+        ],
+    );
+    writeln!(out, "min_blob_length = {}", config.min_blob_length).unwrap();
+    writeln!(out, "collapse = {}", config.collapse).unwrap();
+    writeln!(out, "collapse_min_length = {}", config.collapse_min_length).unwrap();
+}
+
+/// `[hostnames]` carries its suffix allowlist next to the styles.
+fn push_hostnames(out: &mut String, config: &HostnameConfig) {
+    push_table(out, "hostnames", &[("segment", config.segment), ("dot", config.dot)]);
+    let suffixes: Vec<String> = config.suffixes.iter().map(|s| format!("{s:?}")).collect();
+    writeln!(out, "suffixes = [{}]", suffixes.join(", ")).unwrap();
+}
+
+/// `[key_value_pairs]` carries its separators and key characters next to the
+/// styles.
+fn push_key_value_pairs(out: &mut String, config: &KeyValueConfig) {
+    push_table(
+        out,
+        "key_value_pairs",
+        &[
+            ("key", config.key),
+            ("separator", config.separator),
+            ("value", config.value),
+        ],
+    );
+    let separators: Vec<String> = config.separators.iter().map(|s| format!("{s:?}")).collect();
+    writeln!(out, "separators = [{}]", separators.join(", ")).unwrap();
+    writeln!(out, "key_chars = {:?}", config.key_chars).unwrap();
+}
+
+/// `[json]` carries its depth-cycled bracket styles as an array.
+fn push_json(out: &mut String, config: &JsonConfig) {
+    push_table(
+        out,
+        "json",
+        &[
+            ("key", config.key),
+            ("quote_token", config.quote_token),
+            ("curly_bracket", config.curly_bracket),
+            ("square_bracket", config.square_bracket),
+            ("comma", config.comma),
+            ("colon", config.colon),
+            ("string", config.string),
+            ("number", config.number),
+            ("boolean", config.boolean),
+            ("null", config.null),
+        ],
+    );
+    let brackets: Vec<String> = config.brackets.iter().map(|style| inline_style(*style)).collect();
+    writeln!(out, "brackets = [{}]", brackets.join(", ")).unwrap();
+}
+
+/// `[trace_ids]` carries its hashed id styles as an array.
+fn push_trace_ids(out: &mut String, config: &TraceIdConfig) {
+    push_table(
+        out,
+        "trace_ids",
+        &[
+            ("version", config.version),
+            ("trace_id", config.trace_id),
+            ("span_id", config.span_id),
+            ("flags", config.flags),
+            ("separator", config.separator),
+        ],
+    );
+    let ids: Vec<String> = config.ids.iter().map(|style| inline_style(*style)).collect();
+    writeln!(out, "ids = [{}]", ids.join(", ")).unwrap();
+}
+
 /// A style as a TOML inline table, e.g. `{ fg = "magenta", italic = true }`.
 fn inline_style(style: Style) -> String {
     let table = toml::Value::try_from(style).expect("styles serialize to tables");
@@ -119,5 +254,16 @@ mod tests {
         assert_eq!(theme.quotes.quote_token, b'"');
         assert_eq!(theme.ipv4.separator, IpV4Config::default().separator);
         assert_eq!(theme.ipv6.letter, IpV6Config::default().letter);
+        assert_eq!(theme.hostnames.suffixes, HostnameConfig::default().suffixes);
+        assert_eq!(theme.key_value_pairs.separators, KeyValueConfig::default().separators);
+        assert_eq!(theme.key_value_pairs.key_chars, KeyValueConfig::default().key_chars);
+        assert_eq!(theme.json.null, JsonConfig::default().null);
+        assert_eq!(theme.json.brackets, JsonConfig::default().brackets);
+        assert_eq!(theme.trace_ids.ids, TraceIdConfig::default().ids);
+        assert_eq!(theme.epochs.annotation, EpochConfig::default().annotation);
+        assert_eq!(theme.epochs.json, EpochConfig::default().json);
+        assert_eq!(theme.epochs.annotate, EpochConfig::default().annotate);
+        assert_eq!(theme.tokens.min_blob_length, TokenConfig::default().min_blob_length);
+        assert_eq!(theme.tokens.collapse, TokenConfig::default().collapse);
     }
 }
@@ -18,64 +18,151 @@ pub(crate) fn build_highlighter(
         regexes,
         numbers,
         uuids,
+        hashes,
         quotes,
         ipv4,
         ipv6,
+        mac_addresses,
         dates,
         durations,
+        sizes,
+        epochs,
+        permissions,
         paths,
         urls,
         emails,
+        hostnames,
         pointers,
         processes,
+        syslog,
         key_value_pairs,
         json,
         jvm_stack_traces,
+        python_tracebacks,
+        go_panics,
+        rust_backtraces,
+        js_dotnet_stack_traces,
+        windows_paths,
+        xml,
+        sql,
+        diff,
+        cloud_resources,
+        placeholders,
+        tokens,
+        trace_ids,
     } = theme;
 
     let keywords = collect_keywords(color_word, base.contains(Base::Keywords), keywords);
 
     let mut b = Highlighter::builder();
 
+    // Ahead of JSON, whose number style would otherwise claim JSON epochs.
+    if base.contains(Base::Epochs) {
+        b = b.with_epoch_highlighter(epochs);
+    }
+    // Ahead of JSON, so a placeholder left in a JSON string still stands out.
+    if extras.contains(&Extra::Placeholders) {
+        b = b.with_placeholder_highlighter(placeholders);
+    }
+    // Ahead of JSON and numbers, which would otherwise fragment a token.
+    if extras.contains(&Extra::Tokens) {
+        b = b.with_token_highlighter(tokens);
+    }
     if base.contains(Base::Json) {
         b = b.with_json_highlighter(json);
     }
+    if extras.contains(&Extra::Xml) {
+        b = b.with_xml_highlighter(xml);
+    }
+    if extras.contains(&Extra::Sql) {
+        b = b.with_sql_highlighter(sql);
+    }
+    if extras.contains(&Extra::Diff) {
+        b = b.with_diff_highlighter(diff);
+    }
 
     b = regexes.into_iter().fold(b, HighlighterBuilder::with_regex_highlighter);
 
+    if base.contains(Base::Syslog) {
+        b = b.with_syslog_highlighter(syslog);
+    }
+    // Ahead of dates and IPv6: `00:12:34:56:78:9a` holds a time, and an
+    // EUI-64 in colon form also parses as IPv6.
+    if extras.contains(&Extra::MacAddress) {
+        b = b.with_mac_address_highlighter(mac_addresses);
+    }
     if base.contains(Base::Dates) {
         b = b.with_date_time_highlighter(dates);
     }
     if base.contains(Base::Ipv4) {
         b = b.with_ip_v4_highlighter(ipv4);
     }
+    // Ahead of IPv6, which would otherwise claim the `e::` in `core::`.
+    if extras.contains(&Extra::RustBacktrace) {
+        b = b.with_rust_backtrace_highlighter(rust_backtraces);
+    }
     if extras.contains(&Extra::Ipv6) {
         b = b.with_ip_v6_highlighter(ipv6);
     }
     if extras.contains(&Extra::JvmStackTrace) {
         b = b.with_jvm_stack_trace_highlighter(jvm_stack_traces);
     }
+    if extras.contains(&Extra::PythonTraceback) {
+        b = b.with_python_traceback_highlighter(python_tracebacks);
+    }
+    if extras.contains(&Extra::GoPanic) {
+        b = b.with_go_panic_highlighter(go_panics);
+    }
+    if extras.contains(&Extra::JsDotnetStackTrace) {
+        b = b.with_js_dotnet_stack_trace_highlighter(js_dotnet_stack_traces);
+    }
+    if extras.contains(&Extra::WindowsPath) {
+        b = b.with_windows_path_highlighter(windows_paths);
+    }
     if base.contains(Base::Urls) {
         b = b.with_url_highlighter(urls);
     }
     if base.contains(Base::Emails) {
         b = b.with_email_highlighter(emails);
     }
+    // Ahead of hostnames and paths, which would otherwise claim the
+    // `deployment.apps` and `/api` of `deployment.apps/api`.
+    if extras.contains(&Extra::CloudResources) {
+        b = b.with_cloud_resource_highlighter(cloud_resources);
+    }
+    if extras.contains(&Extra::Hostnames) {
+        b = b.with_hostname_highlighter(hostnames);
+    }
     if base.contains(Base::Paths) {
         b = b.with_unix_path_highlighter(paths);
     }
+    // Ahead of key-value pairs, UUIDs and hashes, which would otherwise claim
+    // the ids.
+    if extras.contains(&Extra::TraceIds) {
+        b = b.with_trace_id_highlighter(trace_ids);
+    }
     if base.contains(Base::KeyValuePairs) {
         b = b.with_key_value_highlighter(key_value_pairs);
     }
     if base.contains(Base::Uuids) {
         b = b.with_uuid_highlighter(uuids);
     }
+    if base.contains(Base::Hashes) {
+        b = b.with_hash_highlighter(hashes);
+    }
     if base.contains(Base::Pointers) {
         b = b.with_pointer_highlighter(pointers);
     }
     if base.contains(Base::Processes) {
         b = b.with_unix_process_highlighter(processes);
     }
+    // Ahead of numbers, which would otherwise claim the digits of `0644`.
+    if base.contains(Base::Permissions) {
+        b = b.with_permission_highlighter(permissions);
+    }
+    if base.contains(Base::Sizes) {
+        b = b.with_size_highlighter(sizes);
+    }
     if base.contains(Base::Durations) {
         b = b.with_duration_highlighter(durations);
     }
@@ -21,7 +21,7 @@ use clap::builder::StyledStr;
 use clap::{ArgAction, Parser, ValueEnum};
 use nu_ansi_term::Style;
 use std::error::Error;
-use std::io::{IsTerminal, stdin};
+use std::io::{IsTerminal, stdin, stdout};
 use std::path::PathBuf;
 use tailspin::Highlighter;
 use tailspin::style::Color;
@@ -161,8 +161,22 @@ fn parse_color(s: &str) -> Result<Color, String> {
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Hash)]
 pub enum Extra {
+    CloudResources,
+    Diff,
+    GoPanic,
+    Hostnames,
     Ipv6,
+    JsDotnetStackTrace,
     JvmStackTrace,
+    MacAddress,
+    Placeholders,
+    PythonTraceback,
+    RustBacktrace,
+    Sql,
+    Tokens,
+    TraceIds,
+    WindowsPath,
+    Xml,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Hash)]
@@ -180,6 +194,11 @@ pub enum Base {
     Ipv4,
     Processes,
     Json,
+    Syslog,
+    Hashes,
+    Sizes,
+    Epochs,
+    Permissions,
     Keywords,
 }
 
@@ -219,7 +238,12 @@ pub fn get_config() -> Result<FullConfig> {
     let base = BaseSet::resolve(&cli.enabled, &cli.disabled)?;
     let extras = resolve_extras(&cli.extras);
 
-    let theme = reader::parse_theme(cli.theme.as_ref())?;
+    let mut theme = reader::parse_theme(cli.theme.as_ref())?;
+    // Collapsing drops token text from the output, so it is for a terminal
+    // only: the pager's temp file and piped output keep every token whole.
+    if !(matches!(target, Target::Stdout) && stdout().is_terminal()) {
+        theme.tokens.collapse = false;
+    }
     let highlighter = build_highlighter(&base, &extras, theme, &cli.color_word)?;
 
     Ok(FullConfig {
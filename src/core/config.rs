@@ -21,15 +21,44 @@ pub struct UuidConfig {
     pub separator: Style,
 }
 
+/// Configuration for highlighting hashes and digests (git SHAs, `sha256:…`
+/// digests, container IDs).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HashConfig {
+    /// Style applied to the algorithm prefix (e.g. `sha256`).
+    pub algorithm: Style,
+    /// Style applied to the `:` after the algorithm and a leading `@`.
+    pub separator: Style,
+    /// Style applied to numeric characters.
+    pub number: Style,
+    /// Style applied to alphabetic characters.
+    pub letter: Style,
+}
+
 /// Configuration for highlighting durations (e.g. `150ms`, `2.5s`).
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct DurationConfig {
+    /// Style applied to the numeric values.
+    pub value: Style,
+    /// Style applied to the separators (`.`, and `:` or `,` in clocks).
+    pub separator: Style,
+    /// Style applied to the time units (`ms`, `h`, `days`) and ISO 8601
+    /// designators (`P`, `T`, `H`).
+    pub unit: Style,
+}
+
+/// Configuration for highlighting sizes and rates (e.g. `512KiB`, `1.5 GB`,
+/// `300 req/s`, `99%`).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SizeConfig {
     /// Style applied to the numeric value.
     pub value: Style,
     /// Style applied to the decimal separator (`.`).
     pub separator: Style,
-    /// Style applied to the time unit (`ns`, `us`, `ms`, `s`, `m`, `h`).
+    /// Style applied to the unit (`KiB`, `MB/s`, `Gbps`, `req/s`, `%`, ...).
     pub unit: Style,
 }
 
@@ -48,20 +77,29 @@ pub struct EmailConfig {
 }
 
 /// Configuration for highlighting key-value pairs.
-#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct KeyValueConfig {
     /// Style for the key portion.
     pub key: Style,
     /// Style for the separator between key and value.
     pub separator: Style,
+    /// Style for the value: a bare token or a double-quoted string. Applied
+    /// only where no other highlighter styles the value, so numbers, dates and
+    /// the like inside values keep their own styles.
+    pub value: Style,
+    /// Strings accepted between key and value, e.g. `=`, `=>` or `: `.
+    pub separators: Vec<String>,
+    /// Characters allowed in keys besides letters, digits and `_`, e.g. `.-`
+    /// for `http.status=200` and `user-id=4`.
+    pub key_chars: String,
 }
 
 /// Configuration for highlighting date-time strings.
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct DateTimeConfig {
-    /// Style for dates.
+    /// Style for dates, including month and weekday names.
     pub date: Style,
     /// Style for times.
     pub time: Style,
@@ -77,8 +115,12 @@ pub struct DateTimeConfig {
 pub struct IpV4Config {
     /// Style for numeric segments.
     pub number: Style,
-    /// Style for dot separators (`.`).
+    /// Style for separators (`.`, and the `/` or `:` before a suffix).
     pub separator: Style,
+    /// Style for the CIDR prefix length (`24` in `10.0.0.0/24`).
+    pub prefix_length: Style,
+    /// Style for the port (`8080` in `10.0.0.1:8080`).
+    pub port: Style,
 }
 
 /// Configuration for highlighting IPv6 addresses.
@@ -89,10 +131,41 @@ pub struct IpV6Config {
     pub number: Style,
     /// Style for alphabetic characters.
     pub letter: Style,
-    /// Style for colon separators (`:`).
+    /// Style for separators (`:`, `.`, `/` and the brackets in `[::1]:443`).
+    pub separator: Style,
+    /// Style for the CIDR prefix length (`10` in `fe80::/10`).
+    pub prefix_length: Style,
+    /// Style for the port of a bracketed address (`443` in `[::1]:443`).
+    pub port: Style,
+}
+
+/// Configuration for highlighting MAC addresses (EUI-48 and EUI-64).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MacAddressConfig {
+    /// Style for numeric characters.
+    pub number: Style,
+    /// Style for alphabetic characters.
+    pub letter: Style,
+    /// Style for separators (`:`, `-` or `.`).
     pub separator: Style,
 }
 
+/// Configuration for highlighting bare hostnames and FQDNs (e.g.
+/// `db-3.prod.eu-west-1.internal`).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HostnameConfig {
+    /// Style for hostname labels.
+    pub segment: Style,
+    /// Style for dot separators.
+    pub dot: Style,
+    /// Suffixes a hostname must end with, e.g. `internal` or `co.uk`. Matched
+    /// in lowercase only; a leading dot is optional. Keeps file and package
+    /// names such as `config.yaml` or `com.example.Foo` from matching.
+    pub suffixes: Vec<String>,
+}
+
 /// Configuration for highlighting URLs.
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
@@ -147,9 +220,42 @@ pub struct UnixProcessConfig {
     pub bracket: Style,
 }
 
-/// Configuration for highlighting JSON structures.
+/// Configuration for highlighting syslog headers (RFC 3164 and RFC 5424).
+///
+/// The `app`, `pid` and `bracket` styles mirror [`UnixProcessConfig`]'s
+/// `name`, `id` and `bracket` for the `app[pid]` tag.
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
+pub struct SyslogConfig {
+    /// Style for the `<PRI>` priority prefix.
+    pub priority: Style,
+    /// Style for the RFC 5424 protocol version.
+    pub version: Style,
+    /// Style for the header timestamp (e.g. `Oct 17 12:00:01`).
+    pub timestamp: Style,
+    /// Style for the hostname.
+    pub host: Style,
+    /// Style for the application name (the tag).
+    pub app: Style,
+    /// Style for the process ID.
+    pub pid: Style,
+    /// Style for brackets around the process ID and structured-data elements.
+    pub bracket: Style,
+    /// Style for the RFC 5424 message ID.
+    pub msgid: Style,
+    /// Style for structured-data element IDs (e.g. `exampleSDID@32473`).
+    pub sd_id: Style,
+    /// Style for structured-data parameter names.
+    pub sd_key: Style,
+    /// Style for structured-data parameter values, including their quotes.
+    pub sd_value: Style,
+    /// Style for the tag's trailing `:`, `=` in parameters, and `-` nil values.
+    pub separator: Style,
+}
+
+/// Configuration for highlighting JSON structures.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct JsonConfig {
     /// Style for JSON keys.
     pub key: Style,
@@ -163,6 +269,17 @@ pub struct JsonConfig {
     pub comma: Style,
     /// Style for colons (`:`).
     pub colon: Style,
+    /// Style for the content of string values.
+    pub string: Style,
+    /// Style for number values.
+    pub number: Style,
+    /// Style for `true` and `false`.
+    pub boolean: Style,
+    /// Style for `null`.
+    pub null: Style,
+    /// Bracket styles cycled by nesting depth ("rainbow brackets"). When
+    /// empty, `curly_bracket` and `square_bracket` are used instead.
+    pub brackets: Vec<Style>,
 }
 
 /// Configuration for highlighting quoted text.
@@ -219,6 +336,298 @@ pub struct JvmStackTraceConfig {
     pub line_number: Style,
 }
 
+/// Configuration for highlighting Python tracebacks.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PythonTracebackConfig {
+    /// Style for the `Traceback (most recent call last):` header and the
+    /// chained-exception separators (`During handling of the above exception…`).
+    pub marker: Style,
+    /// Style for the surrounding scaffold: `File "`, `", line `, `, in `, the
+    /// exception's `:` and the `~~^^` underline.
+    pub frame: Style,
+    /// Style for the source file path.
+    pub file: Style,
+    /// Style for the line number.
+    pub line_number: Style,
+    /// Style for the function name (or `<module>`).
+    pub function: Style,
+    /// Style for the module portion of the exception class name.
+    pub module: Style,
+    /// Style for the exception class name itself.
+    pub exception: Style,
+}
+
+/// Configuration for highlighting Go panics and goroutine dumps.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GoPanicConfig {
+    /// Style for the `panic:`, `fatal error:` and `created by` markers.
+    pub marker: Style,
+    /// Style for the surrounding scaffold: `goroutine`, brackets, parentheses,
+    /// `:` separators and the `+` before an offset.
+    pub frame: Style,
+    /// Style for goroutine IDs.
+    pub goroutine_id: Style,
+    /// Style for the goroutine state (e.g. `running`, `chan receive`).
+    pub state: Style,
+    /// Style for the import path and package name before the function.
+    pub package: Style,
+    /// Style for the function name, including any method receiver.
+    pub function: Style,
+    /// Style for the source file path.
+    pub file: Style,
+    /// Style for the line number.
+    pub line_number: Style,
+    /// Style for digits in `+0x` offsets and hex arguments; defaults to the
+    /// `[pointers]` style.
+    pub number: Style,
+    /// Style for hex letters in `+0x` offsets and hex arguments.
+    pub letter: Style,
+    /// Style for the `x` in `+0x` offsets and hex arguments.
+    pub x: Style,
+}
+
+/// Configuration for highlighting Rust panic messages and backtraces.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RustBacktraceConfig {
+    /// Style for the quoted thread name (e.g. `'main'`).
+    pub thread: Style,
+    /// Style for the `panicked at` and `stack backtrace:` markers.
+    pub marker: Style,
+    /// Style for the surrounding scaffold: `thread`, `at`, and `:` separators.
+    pub frame: Style,
+    /// Style for source file paths.
+    pub file: Style,
+    /// Style for line and column numbers.
+    pub line_number: Style,
+    /// Style for the frame index (e.g. `12` in `12: mycrate::func`).
+    pub frame_index: Style,
+    /// Style for crate path segments in frame symbols.
+    pub path: Style,
+    /// Style for `::` path separators.
+    pub separator: Style,
+    /// Style for whole frames and locations inside `std`, `core`, `alloc`,
+    /// `tokio` and crates.io dependencies.
+    pub runtime: Style,
+}
+
+/// Configuration for highlighting JavaScript (V8/Node.js) and .NET stack
+/// traces. The fields mirror [`JvmStackTraceConfig`].
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct JsDotnetStackTraceConfig {
+    /// Style for inner-exception markers: `--->`, `[cause]:` and
+    /// `--- End of inner exception stack trace ---`.
+    pub caused_by: Style,
+    /// Style for the namespace portion of the exception class name.
+    pub package: Style,
+    /// Style for the exception class name itself.
+    pub exception: Style,
+    /// Style for the surrounding scaffold: `at <function> (`, `)`, ` in `,
+    /// `:line ` and `:` separators.
+    pub frame: Style,
+    /// Style for the source file path or URL.
+    pub file: Style,
+    /// Style for placeholders such as `<anonymous>`, `native` and `index 0`,
+    /// and for Node error codes.
+    pub unknown_source: Style,
+    /// Style for line and column numbers.
+    pub line_number: Style,
+}
+
+/// Configuration for highlighting Windows file paths.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WindowsPathConfig {
+    /// Style for the path root: the drive letter (`C:`), the UNC server and
+    /// share, or a `%VAR%` environment variable.
+    pub drive: Style,
+    /// Style for path segments.
+    pub segment: Style,
+    /// Style for path separators (`\` and `/`).
+    pub separator: Style,
+}
+
+/// Configuration for highlighting XML fragments.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct XmlConfig {
+    /// Style for tag brackets (`<`, `>`, `</`, `/>`, `<?`, `?>`).
+    pub bracket: Style,
+    /// Style for element and processing-instruction names.
+    pub tag: Style,
+    /// Style for attribute names.
+    pub attribute: Style,
+    /// Style for the `=` between an attribute name and its value.
+    pub equals: Style,
+    /// Style for attribute values, quotes included.
+    pub value: Style,
+    /// Style for comments and CDATA sections, delimiters included.
+    pub comment: Style,
+}
+
+/// Configuration for highlighting SQL statements.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SqlConfig {
+    /// Style for keywords such as `SELECT` and `WHERE`, in any case.
+    pub keyword: Style,
+    /// Style for table, column and alias names, quoted or bare.
+    pub identifier: Style,
+    /// Style for bind parameters (`$1`, `?`, `:name`).
+    pub parameter: Style,
+    /// Style for `'...'` string literals, quotes included.
+    pub string: Style,
+    /// Style for numeric literals.
+    pub number: Style,
+}
+
+/// Configuration for highlighting diff output.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DiffConfig {
+    /// Style for `diff --git`, `index`, `---` and `+++` lines.
+    pub header: Style,
+    /// Style for the `@@ -12,7 +12,8 @@` range of a hunk header.
+    pub hunk: Style,
+    /// Style for added lines.
+    pub added: Style,
+    /// Style for removed lines.
+    pub removed: Style,
+    /// Style for changed (`~`) and replaced (`-/+`) lines.
+    pub changed: Style,
+    /// Style for context lines. Unstyled by default, since any line indented
+    /// by a space looks like one.
+    pub context: Style,
+}
+
+/// Configuration for highlighting Unix epoch timestamps.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EpochConfig {
+    /// Style for the epoch value.
+    pub style: Style,
+    /// Style for the decoded time appended when `annotate` is set.
+    pub annotation: Style,
+    /// Match values after a `key=` separator (`ts=1760702400`).
+    pub key_value: bool,
+    /// Match JSON values (`"time":1760702400123`).
+    pub json: bool,
+    /// Match plausible epochs anywhere, regardless of context.
+    pub anywhere: bool,
+    /// Append the decoded ISO-8601 UTC time after each epoch.
+    pub annotate: bool,
+}
+
+/// Configuration for highlighting cloud resource references: Kubernetes
+/// `kind/name`, AWS ARNs and GCP resource paths.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CloudResourceConfig {
+    /// Style for Kubernetes kinds, GCP collection names and the `arn` prefix.
+    pub kind: Style,
+    /// Style for Kubernetes names and GCP ids.
+    pub name: Style,
+    /// Style for generated pod and `ReplicaSet` suffixes (`-7d9f8b6c5d-x2k4q`).
+    pub hash: Style,
+    /// Style for the ARN partition (`aws`, `aws-cn`).
+    pub partition: Style,
+    /// Style for the ARN service (`iam`, `s3`).
+    pub service: Style,
+    /// Style for the ARN region.
+    pub region: Style,
+    /// Style for the ARN account id.
+    pub account: Style,
+    /// Style for the ARN resource (`role/deploy`).
+    pub resource: Style,
+    /// Style for `/` and `:` separators.
+    pub separator: Style,
+}
+
+/// Configuration for highlighting file permissions, symbolic (`drwxr-xr-x`)
+/// or octal (`0o755`, `chmod 755`, `mode=0644`).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PermissionConfig {
+    /// Style for the file type (`d`, `l`, ...).
+    pub file_type: Style,
+    /// Style for read permission, and octal digits granting only read.
+    pub read: Style,
+    /// Style for write permission, and octal digits granting write.
+    pub write: Style,
+    /// Style for execute permission, and octal digits granting execute but not
+    /// write.
+    pub execute: Style,
+    /// Style for setuid, setgid and sticky bits.
+    pub special: Style,
+    /// Style for write permission granted to everyone.
+    pub world_writable: Style,
+    /// Style for unset bits (`-`, octal `0`) and the `0`/`0o` prefix.
+    pub none: Style,
+}
+
+/// Configuration for highlighting variable placeholders.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PlaceholderConfig {
+    /// Style for the sigil and braces (`$`, `${`, `}`, `%`, `{{`).
+    pub sigil: Style,
+    /// Style for the variable name or template expression.
+    pub name: Style,
+}
+
+/// Configuration for highlighting JWTs, `Bearer` tokens and base64 blobs.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TokenConfig {
+    /// Style for the `Bearer` scheme.
+    pub scheme: Style,
+    /// Style for a JWT's header segment.
+    pub header: Style,
+    /// Style for a JWT's payload segment.
+    pub payload: Style,
+    /// Style for a JWT's signature segment.
+    pub signature: Style,
+    /// Style for base64 blobs and opaque `Bearer` tokens.
+    pub blob: Style,
+    /// Style for the dots between JWT segments.
+    pub separator: Style,
+    /// Style for the `…[1.2KB]` marker of a collapsed token.
+    pub collapsed: Style,
+    /// The shortest base64 run highlighted as a blob.
+    pub min_blob_length: usize,
+    /// Show tokens of at least `collapse_min_length` characters as their
+    /// first characters and size (`eyJhbGc…[1.2KB]`). The rest of the token
+    /// is dropped from the highlighted output, so `tspin` honors this only
+    /// when printing straight to a terminal, never into the pager's temp file
+    /// or a pipe.
+    pub collapse: bool,
+    /// The shortest token collapsed when `collapse` is set.
+    pub collapse_min_length: usize,
+}
+
+/// Configuration for highlighting W3C trace context and trace, span and
+/// request ids.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TraceIdConfig {
+    /// Style for the `traceparent` version.
+    pub version: Style,
+    /// Style for trace and request ids when `ids` is empty.
+    pub trace_id: Style,
+    /// Styles for trace and request ids, picked by a hash of the id so that
+    /// the same trace has the same style on every line.
+    pub ids: Vec<Style>,
+    /// Style for span ids.
+    pub span_id: Style,
+    /// Style for the `traceparent` flags.
+    pub flags: Style,
+    /// Style for the dashes between `traceparent` fields.
+    pub separator: Style,
+}
+
 /// Configuration for highlighting custom keywords.
 #[derive(PartialEq, Eq, Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -257,6 +666,16 @@ impl Default for DurationConfig {
     }
 }
 
+impl Default for SizeConfig {
+    fn default() -> Self {
+        SizeConfig {
+            value: Style::new().fg(Color::Blue),
+            separator: Style::new().fg(Color::Green),
+            unit: Style::new().fg(Color::Green).italic(),
+        }
+    }
+}
+
 impl Default for EmailConfig {
     fn default() -> Self {
         EmailConfig {
@@ -278,11 +697,26 @@ impl Default for UuidConfig {
     }
 }
 
+impl Default for HashConfig {
+    fn default() -> Self {
+        HashConfig {
+            algorithm: Style::new().faint(),
+            separator: Style::new().fg(Color::Red),
+            number: Style::new().fg(Color::Blue).italic(),
+            letter: Style::new().fg(Color::Magenta).italic(),
+        }
+    }
+}
+
 impl Default for KeyValueConfig {
     fn default() -> Self {
         KeyValueConfig {
             key: Style::new().faint(),
             separator: Style::new().fg(Color::White),
+            value: Style::new().fg(Color::BrightBlue),
+            // `: ` is left out: `Error: disk full` is not a key-value pair.
+            separators: vec!["=".into(), "=>".into()],
+            key_chars: ".-".into(),
         }
     }
 }
@@ -303,6 +737,8 @@ impl Default for IpV4Config {
         IpV4Config {
             number: Style::new().fg(Color::Blue).italic(),
             separator: Style::new().fg(Color::Red),
+            prefix_length: Style::new().fg(Color::Blue),
+            port: Style::new().fg(Color::Cyan).italic(),
         }
     }
 }
@@ -313,6 +749,60 @@ impl Default for IpV6Config {
             number: Style::new().fg(Color::Blue).italic(),
             letter: Style::new().fg(Color::Magenta).italic(),
             separator: Style::new().fg(Color::Red),
+            prefix_length: IpV4Config::default().prefix_length,
+            port: IpV4Config::default().port,
+        }
+    }
+}
+
+impl Default for MacAddressConfig {
+    fn default() -> Self {
+        MacAddressConfig {
+            number: Style::new().fg(Color::Blue).italic(),
+            letter: Style::new().fg(Color::Magenta).italic(),
+            separator: Style::new().fg(Color::Red),
+        }
+    }
+}
+
+impl Default for HostnameConfig {
+    fn default() -> Self {
+        HostnameConfig {
+            segment: Style::new().fg(Color::Blue),
+            dot: Style::new().fg(Color::Red),
+            // Public TLDs that double as common file extensions (`.md`, `.rs`,
+            // `.sh`, `.py`, `.in`, `.zip`) are deliberately left out.
+            suffixes: [
+                "internal",
+                "local",
+                "localdomain",
+                "lan",
+                "corp",
+                "intranet",
+                "arpa",
+                "com",
+                "net",
+                "org",
+                "edu",
+                "gov",
+                "mil",
+                "io",
+                "dev",
+                "app",
+                "cloud",
+                "co",
+                "eu",
+                "us",
+                "uk",
+                "de",
+                "fr",
+                "nl",
+                "ch",
+                "se",
+                "jp",
+            ]
+            .map(String::from)
+            .to_vec(),
         }
     }
 }
@@ -360,6 +850,27 @@ impl Default for UnixProcessConfig {
     }
 }
 
+impl Default for SyslogConfig {
+    fn default() -> Self {
+        let process = UnixProcessConfig::default();
+
+        SyslogConfig {
+            priority: Style::new().faint(),
+            version: Style::new().faint(),
+            timestamp: Style::new().fg(Color::Blue),
+            host: Style::new().fg(Color::Magenta),
+            app: process.name,
+            pid: process.id,
+            bracket: process.bracket,
+            msgid: Style::new().fg(Color::Green),
+            sd_id: Style::new().fg(Color::Green).italic(),
+            sd_key: Style::new().faint(),
+            sd_value: Style::new().fg(Color::Yellow),
+            separator: Style::new().faint(),
+        }
+    }
+}
+
 impl Default for JsonConfig {
     fn default() -> Self {
         JsonConfig {
@@ -369,6 +880,11 @@ impl Default for JsonConfig {
             square_bracket: Style::new().faint(),
             comma: Style::new().faint(),
             colon: Style::new().faint(),
+            string: QuoteConfig::default().style,
+            number: NumberConfig::default().style,
+            boolean: Style::new().fg(Color::Green).italic(),
+            null: Style::new().fg(Color::Red).italic(),
+            brackets: Vec::new(),
         }
     }
 }
@@ -387,6 +903,208 @@ impl Default for JvmStackTraceConfig {
     }
 }
 
+impl Default for PythonTracebackConfig {
+    fn default() -> Self {
+        PythonTracebackConfig {
+            marker: Style::new().bold(),
+            frame: Style::new().fg(Color::Red).faint(),
+            file: Style::new().fg(Color::Yellow),
+            line_number: Style::new().fg(Color::Cyan),
+            function: Style::new().fg(Color::Green),
+            module: Style::new().fg(Color::Red).faint(),
+            exception: Style::new().fg(Color::Red),
+        }
+    }
+}
+
+impl Default for GoPanicConfig {
+    fn default() -> Self {
+        let pointer = PointerConfig::default();
+        GoPanicConfig {
+            marker: Style::new().fg(Color::Red).bold(),
+            frame: Style::new().fg(Color::Red).faint(),
+            goroutine_id: Style::new().fg(Color::Cyan),
+            state: Style::new().fg(Color::Yellow).italic(),
+            package: Style::new().fg(Color::Green).faint(),
+            function: Style::new().fg(Color::Green),
+            file: Style::new().fg(Color::Yellow),
+            line_number: Style::new().fg(Color::Cyan),
+            number: pointer.number,
+            letter: pointer.letter,
+            x: pointer.x,
+        }
+    }
+}
+
+impl Default for RustBacktraceConfig {
+    fn default() -> Self {
+        RustBacktraceConfig {
+            thread: Style::new().fg(Color::Magenta),
+            marker: Style::new().fg(Color::Red).bold(),
+            frame: Style::new().fg(Color::Red).faint(),
+            file: Style::new().fg(Color::Yellow),
+            line_number: Style::new().fg(Color::Cyan),
+            frame_index: Style::new().fg(Color::Cyan).faint(),
+            path: Style::new().fg(Color::Green),
+            separator: Style::new().faint(),
+            runtime: Style::new().faint(),
+        }
+    }
+}
+
+impl Default for JsDotnetStackTraceConfig {
+    fn default() -> Self {
+        let jvm = JvmStackTraceConfig::default();
+        JsDotnetStackTraceConfig {
+            caused_by: jvm.caused_by,
+            package: jvm.package,
+            exception: jvm.exception,
+            frame: jvm.frame,
+            file: jvm.file,
+            unknown_source: jvm.unknown_source,
+            line_number: jvm.line_number,
+        }
+    }
+}
+
+impl Default for WindowsPathConfig {
+    fn default() -> Self {
+        let unix = UnixPathConfig::default();
+        WindowsPathConfig {
+            drive: Style::new().fg(Color::Green).bold(),
+            segment: unix.segment,
+            separator: unix.separator,
+        }
+    }
+}
+
+impl Default for XmlConfig {
+    fn default() -> Self {
+        XmlConfig {
+            bracket: Style::new().faint(),
+            tag: Style::new().fg(Color::Blue),
+            attribute: Style::new().fg(Color::Magenta),
+            equals: Style::new().faint(),
+            value: QuoteConfig::default().style,
+            comment: Style::new().faint().italic(),
+        }
+    }
+}
+
+impl Default for SqlConfig {
+    fn default() -> Self {
+        SqlConfig {
+            keyword: Style::new().fg(Color::Blue).bold(),
+            identifier: Style::new().fg(Color::Magenta),
+            parameter: Style::new().fg(Color::Red).bold(),
+            string: QuoteConfig::default().style,
+            number: NumberConfig::default().style,
+        }
+    }
+}
+
+impl Default for DiffConfig {
+    fn default() -> Self {
+        DiffConfig {
+            header: Style::new().bold(),
+            hunk: Style::new().fg(Color::Cyan),
+            added: Style::new().fg(Color::Green),
+            removed: Style::new().fg(Color::Red),
+            changed: Style::new().fg(Color::Yellow),
+            context: Style::new(),
+        }
+    }
+}
+
+impl Default for EpochConfig {
+    fn default() -> Self {
+        EpochConfig {
+            style: DateTimeConfig::default().date,
+            annotation: Style::new().faint(),
+            key_value: true,
+            json: true,
+            anywhere: false,
+            annotate: false,
+        }
+    }
+}
+
+impl Default for CloudResourceConfig {
+    fn default() -> Self {
+        CloudResourceConfig {
+            kind: Style::new().fg(Color::Cyan),
+            name: Style::new().fg(Color::Blue),
+            hash: Style::new().faint(),
+            partition: Style::new().fg(Color::Magenta),
+            service: Style::new().fg(Color::Yellow),
+            region: Style::new().fg(Color::Green),
+            account: Style::new().fg(Color::Red),
+            resource: Style::new().fg(Color::Blue),
+            separator: Style::new().faint(),
+        }
+    }
+}
+
+impl Default for PermissionConfig {
+    fn default() -> Self {
+        PermissionConfig {
+            file_type: Style::new().fg(Color::Blue),
+            read: Style::new().fg(Color::Yellow),
+            write: Style::new().fg(Color::Red),
+            execute: Style::new().fg(Color::Green),
+            special: Style::new().fg(Color::Magenta).bold(),
+            world_writable: Style::new().fg(Color::White).on(Color::Red).bold(),
+            none: Style::new().faint(),
+        }
+    }
+}
+
+impl Default for PlaceholderConfig {
+    fn default() -> Self {
+        PlaceholderConfig {
+            sigil: Style::new().fg(Color::Yellow),
+            name: Style::new().fg(Color::Yellow).bold(),
+        }
+    }
+}
+
+impl Default for TokenConfig {
+    fn default() -> Self {
+        TokenConfig {
+            scheme: Style::new().fg(Color::Magenta).bold(),
+            header: Style::new().fg(Color::Red),
+            payload: Style::new().fg(Color::Magenta),
+            signature: Style::new().fg(Color::Cyan),
+            blob: Style::new().fg(Color::Yellow),
+            separator: Style::new().faint(),
+            collapsed: Style::new().faint(),
+            min_blob_length: 32,
+            collapse: false,
+            collapse_min_length: 64,
+        }
+    }
+}
+
+impl Default for TraceIdConfig {
+    fn default() -> Self {
+        TraceIdConfig {
+            version: Style::new().faint(),
+            trace_id: Style::new().fg(Color::Blue),
+            ids: vec![
+                Style::new().fg(Color::Blue),
+                Style::new().fg(Color::Green),
+                Style::new().fg(Color::Yellow),
+                Style::new().fg(Color::Magenta),
+                Style::new().fg(Color::BrightBlue),
+                Style::new().fg(Color::BrightGreen),
+            ],
+            span_id: Style::new().fg(Color::Cyan),
+            flags: Style::new().faint(),
+            separator: Style::new().faint(),
+        }
+    }
+}
+
 impl Default for QuoteConfig {
     fn default() -> Self {
         QuoteConfig {
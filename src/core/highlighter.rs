@@ -1,23 +1,43 @@
 use crate::core::config::*;
 use crate::core::span_pipeline::Pipeline;
+use crate::core::span_pipeline::finders::cloud_resource::CloudResourceFinder;
 use crate::core::span_pipeline::finders::date_dash::DateDashFinder;
+use crate::core::span_pipeline::finders::date_name::DateNameFinder;
 use crate::core::span_pipeline::finders::date_time::DateTimeFinder;
+use crate::core::span_pipeline::finders::diff::DiffFinder;
 use crate::core::span_pipeline::finders::duration::DurationFinder;
 use crate::core::span_pipeline::finders::email::EmailFinder;
+use crate::core::span_pipeline::finders::epoch::EpochFinder;
+use crate::core::span_pipeline::finders::go_panic::GoPanicFinder;
+use crate::core::span_pipeline::finders::hash::HashFinder;
+use crate::core::span_pipeline::finders::hostname::HostnameFinder;
 use crate::core::span_pipeline::finders::ip_v4::IpV4Finder;
 use crate::core::span_pipeline::finders::ip_v6::IpV6Finder;
+use crate::core::span_pipeline::finders::js_dotnet_stack::JsDotnetStackFinder;
 use crate::core::span_pipeline::finders::json::JsonFinder;
 use crate::core::span_pipeline::finders::jvm_stack::JvmStackFinder;
 use crate::core::span_pipeline::finders::key_value::KeyValueFinder;
 use crate::core::span_pipeline::finders::keyword::KeywordFinder;
+use crate::core::span_pipeline::finders::mac_address::MacAddressFinder;
 use crate::core::span_pipeline::finders::number::NumberFinder;
+use crate::core::span_pipeline::finders::permission::PermissionFinder;
+use crate::core::span_pipeline::finders::placeholder::PlaceholderFinder;
 use crate::core::span_pipeline::finders::pointer::PointerFinder;
+use crate::core::span_pipeline::finders::python_traceback::PythonTracebackFinder;
 use crate::core::span_pipeline::finders::quote::QuoteFinder;
 use crate::core::span_pipeline::finders::regex::RegexFinder;
+use crate::core::span_pipeline::finders::rust_backtrace::RustBacktraceFinder;
+use crate::core::span_pipeline::finders::size::SizeFinder;
+use crate::core::span_pipeline::finders::sql::SqlFinder;
+use crate::core::span_pipeline::finders::syslog::SyslogFinder;
+use crate::core::span_pipeline::finders::token::TokenFinder;
+use crate::core::span_pipeline::finders::trace_id::TraceIdFinder;
 use crate::core::span_pipeline::finders::unix_path::UnixPathFinder;
 use crate::core::span_pipeline::finders::unix_process::UnixProcessFinder;
 use crate::core::span_pipeline::finders::url::UrlFinder;
 use crate::core::span_pipeline::finders::uuid::UuidFinder;
+use crate::core::span_pipeline::finders::windows_path::WindowsPathFinder;
+use crate::core::span_pipeline::finders::xml::XmlFinder;
 use crate::core::span_pipeline::palette::Palette;
 use crate::core::span_pipeline::span::Finder;
 use std::borrow::Cow;
@@ -75,7 +95,9 @@ impl Default for Highlighter {
     /// This operation is expensive and should be done once and reused.
     fn default() -> Self {
         Highlighter::builder()
+            .with_epoch_highlighter(EpochConfig::default())
             .with_json_highlighter(JsonConfig::default())
+            .with_syslog_highlighter(SyslogConfig::default())
             .with_date_time_highlighter(DateTimeConfig::default())
             .with_ip_v4_highlighter(IpV4Config::default())
             .with_url_highlighter(UrlConfig::default())
@@ -83,8 +105,11 @@ impl Default for Highlighter {
             .with_unix_path_highlighter(UnixPathConfig::default())
             .with_key_value_highlighter(KeyValueConfig::default())
             .with_uuid_highlighter(UuidConfig::default())
+            .with_hash_highlighter(HashConfig::default())
             .with_pointer_highlighter(PointerConfig::default())
             .with_unix_process_highlighter(UnixProcessConfig::default())
+            .with_permission_highlighter(PermissionConfig::default())
+            .with_size_highlighter(SizeConfig::default())
             .with_duration_highlighter(DurationConfig::default())
             .with_number_highlighter(NumberConfig::default())
             .with_quote_highlighter(QuoteConfig::default())
@@ -142,6 +167,35 @@ impl HighlighterBuilder {
         self
     }
 
+    /// Adds a highlighter for Unix epoch timestamps in seconds, milliseconds,
+    /// microseconds or nanoseconds.
+    pub fn with_epoch_highlighter(mut self, config: EpochConfig) -> Self {
+        let finder = EpochFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for symbolic and octal file permissions.
+    pub fn with_permission_highlighter(mut self, config: PermissionConfig) -> Self {
+        let finder = PermissionFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for sizes and rates (`512KiB`, `1.5 GB`, `300 req/s`, `99%`).
+    pub fn with_size_highlighter(mut self, config: SizeConfig) -> Self {
+        let finder = SizeFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for hashes and digests (git SHAs, `sha256:…`, container IDs).
+    pub fn with_hash_highlighter(mut self, config: HashConfig) -> Self {
+        let finder = HashFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
     /// Adds a highlighter for Unix file paths.
     pub fn with_unix_path_highlighter(mut self, config: UnixPathConfig) -> Self {
         let finder = UnixPathFinder::new(config, &mut self.palette);
@@ -172,6 +226,9 @@ impl HighlighterBuilder {
 
     /// Adds a highlighter for dates and times.
     pub fn with_date_time_highlighter(mut self, config: DateTimeConfig) -> Self {
+        // First, so the time in `17/Oct/2026:12:00:00` is not read as `6:12:00:00`.
+        let date_name = DateNameFinder::new(config, &mut self.palette);
+        self.add_finder(date_name);
         let date_time = DateTimeFinder::new(config, &mut self.palette);
         self.add_finder(date_time);
         let date_dash = DateDashFinder::new(config, &mut self.palette);
@@ -179,6 +236,13 @@ impl HighlighterBuilder {
         self
     }
 
+    /// Adds a highlighter for syslog headers (RFC 3164 and RFC 5424).
+    pub fn with_syslog_highlighter(mut self, config: SyslogConfig) -> Self {
+        let finder = SyslogFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
     /// Adds a highlighter for IPv6 addresses.
     pub fn with_ip_v6_highlighter(mut self, config: IpV6Config) -> Self {
         let finder = IpV6Finder::new(config, &mut self.palette);
@@ -186,6 +250,20 @@ impl HighlighterBuilder {
         self
     }
 
+    /// Adds a highlighter for bare hostnames ending in one of the configured suffixes.
+    pub fn with_hostname_highlighter(mut self, config: HostnameConfig) -> Self {
+        let finder = HostnameFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for MAC addresses in colon, dash and Cisco dotted form.
+    pub fn with_mac_address_highlighter(mut self, config: MacAddressConfig) -> Self {
+        let finder = MacAddressFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
     /// Adds a highlighter for JVM stack traces (Java, Kotlin, Scala, etc.).
     pub fn with_jvm_stack_trace_highlighter(mut self, config: JvmStackTraceConfig) -> Self {
         let finder = JvmStackFinder::new(config, &mut self.palette);
@@ -193,6 +271,90 @@ impl HighlighterBuilder {
         self
     }
 
+    /// Adds a highlighter for Python tracebacks.
+    pub fn with_python_traceback_highlighter(mut self, config: PythonTracebackConfig) -> Self {
+        let finder = PythonTracebackFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for Go panics and goroutine dumps.
+    pub fn with_go_panic_highlighter(mut self, config: GoPanicConfig) -> Self {
+        let finder = GoPanicFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for Rust panic messages and `RUST_BACKTRACE` frames.
+    pub fn with_rust_backtrace_highlighter(mut self, config: RustBacktraceConfig) -> Self {
+        let finder = RustBacktraceFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for JavaScript (V8/Node.js) and .NET stack traces.
+    pub fn with_js_dotnet_stack_trace_highlighter(mut self, config: JsDotnetStackTraceConfig) -> Self {
+        let finder = JsDotnetStackFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for Windows file paths.
+    pub fn with_windows_path_highlighter(mut self, config: WindowsPathConfig) -> Self {
+        let finder = WindowsPathFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for well-formed XML fragments.
+    pub fn with_xml_highlighter(mut self, config: XmlConfig) -> Self {
+        let finder = XmlFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for SQL statements.
+    pub fn with_sql_highlighter(mut self, config: SqlConfig) -> Self {
+        let finder = SqlFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for unified diff and `terraform plan` lines.
+    pub fn with_diff_highlighter(mut self, config: DiffConfig) -> Self {
+        let finder = DiffFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for Kubernetes references, AWS ARNs and GCP resource paths.
+    pub fn with_cloud_resource_highlighter(mut self, config: CloudResourceConfig) -> Self {
+        let finder = CloudResourceFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for variable placeholders: `$VAR`, `${VAR}`, `%VAR%`, `{{ .expr }}` and `{name}`.
+    pub fn with_placeholder_highlighter(mut self, config: PlaceholderConfig) -> Self {
+        let finder = PlaceholderFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for JWTs, `Bearer` tokens and long base64 blobs.
+    pub fn with_token_highlighter(mut self, config: TokenConfig) -> Self {
+        let finder = TokenFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for W3C trace context, trace, span and request ids.
+    pub fn with_trace_id_highlighter(mut self, config: TraceIdConfig) -> Self {
+        let finder = TraceIdFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
     /// Adds a highlighter for IPv4 addresses.
     pub fn with_ip_v4_highlighter(mut self, config: IpV4Config) -> Self {
         let finder = IpV4Finder::new(config, &mut self.palette);
@@ -368,6 +530,40 @@ mod tests {
         assert_eq!(actual.to_string().convert_escape_codes(), expected);
     }
 
+    #[test]
+    fn durations_inside_paths_and_urls_keep_their_owner() {
+        // Durations run after paths and URLs, so `5m` stays part of them
+        // instead of being split out.
+        let path = Highlighter::builder()
+            .with_unix_path_highlighter(UnixPathConfig::default())
+            .build()
+            .unwrap();
+        let url = Highlighter::builder()
+            .with_url_highlighter(UrlConfig::default())
+            .build()
+            .unwrap();
+        let default = Highlighter::default();
+
+        for (input, owner) in [
+            ("/tmp/cache/5m/data", &path),
+            ("https://example.com/cache?ttl=5m", &url),
+        ] {
+            assert_eq!(default.apply(input), owner.apply(input), "{input}");
+        }
+    }
+
+    #[test]
+    fn default_highlighter_keeps_duration_clocks_whole() {
+        let output = Highlighter::default().apply("took 00:01:23.456").to_string();
+        let plain = Highlighter::builder()
+            .with_duration_highlighter(DurationConfig::default())
+            .build()
+            .unwrap()
+            .apply("took 00:01:23.456")
+            .to_string();
+        assert_eq!(output, plain);
+    }
+
     #[test]
     fn invalid_regex_fails_build_and_first_error_wins() {
         let result = Highlighter::builder()
@@ -0,0 +1,330 @@
+use super::build_regex;
+use memchr::memchr2;
+use regex::{Captures, Regex};
+
+use crate::core::config::CloudResourceConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// The alphabet Kubernetes draws generated name suffixes from: no vowels and
+/// no look-alike characters, so ordinary words rarely qualify.
+const HASH_ALPHABET: &[u8] = b"bcdfghjklmnpqrstvwxz2456789";
+
+#[derive(Debug, Clone)]
+pub(crate) struct CloudResourceFinder {
+    regex: Regex,
+    kind: StyleId,
+    name: StyleId,
+    hash: StyleId,
+    partition: StyleId,
+    service: StyleId,
+    region: StyleId,
+    account: StyleId,
+    resource: StyleId,
+    separator: StyleId,
+}
+
+impl CloudResourceFinder {
+    pub fn new(config: CloudResourceConfig, palette: &mut Palette) -> Self {
+        // Kinds are spelled out (plus kubectl's unambiguous short names) so
+        // that an arbitrary `a/b` path does not qualify; `ns/op` in benchmark
+        // output is why `ns` is not among them.
+        let pattern = r"(?x)
+            # AWS: arn:partition:service:region:account:resource
+            (?P<arn>arn)(?P<c1>:)
+            (?P<partition>aws(?:-cn|-us-gov|-iso(?:-[a-z])?)?)(?P<c2>:)
+            (?P<service>[a-z0-9-]+)(?P<c3>:)
+            (?P<region>[a-z0-9-]*)(?P<c4>:)
+            (?P<account>\d{12}|aws)?(?P<c5>:)
+            (?P<resource>[\w+=,.@:/*-]*[\w+=@/*-])
+          |
+            # GCP: projects/my-project/zones/europe-west1-b/instances/vm-1
+            (?P<gcp>projects/[a-z][a-z0-9-]{4,28}[a-z0-9](?:/[a-z][A-Za-z]*/[\w.-]*[\w-])+)
+          |
+            # Kubernetes: kind[.group]/name
+            (?P<kind>
+                (?:
+                    pods? | deployments? | replicasets? | statefulsets? | daemonsets? | jobs? | cronjobs?
+                  | services? | namespaces? | nodes? | configmaps? | secrets? | endpoints
+                  | persistentvolumeclaims? | persistentvolumes? | serviceaccounts? | ingress(?:es)?
+                  | roles? | rolebindings? | clusterroles? | clusterrolebindings?
+                  | horizontalpodautoscalers? | networkpolic(?:y|ies) | events?
+                  | svc | deploy | sts | pvc | hpa
+                )
+                (?:\.[a-z0-9-]+)*
+            )
+            (?P<slash>/)
+            (?P<name>[a-z0-9](?:[a-z0-9.-]*[a-z0-9])?)
+        ";
+
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            kind: palette.intern(config.kind),
+            name: palette.intern(config.name),
+            hash: palette.intern(config.hash),
+            partition: palette.intern(config.partition),
+            service: palette.intern(config.service),
+            region: palette.intern(config.region),
+            account: palette.intern(config.account),
+            resource: palette.intern(config.resource),
+            separator: palette.intern(config.separator),
+        }
+    }
+
+    fn push_arn(&self, caps: &Captures, collector: &mut Collector) {
+        let segments = [
+            ("arn", self.kind),
+            ("c1", self.separator),
+            ("partition", self.partition),
+            ("c2", self.separator),
+            ("service", self.service),
+            ("c3", self.separator),
+            ("region", self.region),
+            ("c4", self.separator),
+            ("account", self.account),
+            ("c5", self.separator),
+        ];
+        for (group, style) in segments {
+            if let Some(m) = caps.name(group) {
+                collector.push(m.start(), m.end(), style);
+            }
+        }
+
+        // `role/deploy`, `function:name:alias`: the separators inside the
+        // resource keep the separator style.
+        let resource = caps.name("resource").unwrap();
+        let mut start = resource.start();
+        for (i, b) in resource.as_str().bytes().enumerate() {
+            if b == b'/' || b == b':' {
+                let at = resource.start() + i;
+                collector.push(start, at, self.resource);
+                collector.push(at, at + 1, self.separator);
+                start = at + 1;
+            }
+        }
+        collector.push(start, resource.end(), self.resource);
+    }
+
+    /// `projects/x/zones/y/instances/z` alternates collection names and ids.
+    fn push_gcp(&self, path: &str, offset: usize, collector: &mut Collector) {
+        let mut start = offset;
+        for (i, segment) in path.split('/').enumerate() {
+            let style = if i % 2 == 0 { self.kind } else { self.name };
+            if start > offset {
+                collector.push(start - 1, start, self.separator);
+            }
+            collector.push(start, start + segment.len(), style);
+            start += segment.len() + 1;
+        }
+    }
+
+    fn push_k8s(&self, caps: &Captures, collector: &mut Collector) {
+        let kind = caps.name("kind").unwrap();
+        let slash = caps.name("slash").unwrap();
+        let name = caps.name("name").unwrap();
+        collector.push(kind.start(), kind.end(), self.kind);
+        collector.push(slash.start(), slash.end(), self.separator);
+
+        let hash_start = name.start() + hash_suffix_start(name.as_str());
+        collector.push(name.start(), hash_start, self.name);
+        if hash_start < name.end() {
+            collector.push(hash_start, name.end(), self.hash);
+        }
+    }
+}
+
+/// Where the generated suffix of a Kubernetes name begins, or `name.len()` if
+/// it has none: a `ReplicaSet`'s pod-template hash (`api-7d9f8b6c5d`) and, for
+/// its pods, a further five-character suffix (`api-7d9f8b6c5d-x2k4q`).
+fn hash_suffix_start(name: &str) -> usize {
+    let is_hash = |segment: &str, lengths: std::ops::RangeInclusive<usize>| {
+        lengths.contains(&segment.len()) && segment.bytes().all(|b| HASH_ALPHABET.contains(&b))
+    };
+
+    let mut end = name.len();
+    if let Some(dash) = name.rfind('-')
+        && dash > 0
+    {
+        let last = &name[dash + 1..];
+        if is_hash(last, 5..=5) {
+            end = dash;
+            if let Some(prev) = name[..dash].rfind('-')
+                && prev > 0
+                && is_hash(&name[prev + 1..dash], 6..=10)
+            {
+                end = prev;
+            }
+        } else if is_hash(last, 6..=10) {
+            end = dash;
+        }
+    }
+    end
+}
+
+impl Finder for CloudResourceFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+        if memchr2(b'/', b':', bytes).is_none() {
+            return;
+        }
+
+        for caps in self.regex.captures_iter(input) {
+            let whole = caps.get(0).unwrap();
+            // Part of a longer path, word or ARN-like token: `/home/me/projects/...`,
+            // the `role/x` of a malformed ARN.
+            let glued_before = whole.start() > 0
+                && matches!(bytes[whole.start() - 1], b'/' | b'.' | b':' | b'-' | b'_' | b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9');
+            let glued_after = bytes
+                .get(whole.end())
+                .is_some_and(|&b| b == b'/' || b.is_ascii_alphanumeric() || b == b'_');
+            if glued_before || glued_after {
+                continue;
+            }
+
+            if caps.name("arn").is_some() {
+                self.push_arn(&caps, collector);
+            } else if let Some(gcp) = caps.name("gcp") {
+                self.push_gcp(gcp.as_str(), gcp.start(), collector);
+            } else {
+                self.push_k8s(&caps, collector);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> CloudResourceFinder {
+        CloudResourceFinder::new(
+            CloudResourceConfig {
+                kind: Style::new().fg(Color::Cyan),
+                name: Style::new().fg(Color::Blue),
+                hash: Style::new().faint(),
+                partition: Style::new().fg(Color::Magenta),
+                service: Style::new().fg(Color::Yellow),
+                region: Style::new().fg(Color::Green),
+                account: Style::new().fg(Color::Red),
+                resource: Style::new().fg(Color::White),
+                separator: Style::new().fg(Color::BrightBlack),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    fn styled(input: &str) -> Vec<(&str, StyleId)> {
+        let mut collector = Collector::new();
+        make_finder().find_spans(input, &mut collector);
+        collector
+            .into_spans()
+            .iter()
+            .map(|s| (&input[s.start..s.end], s.style))
+            .collect()
+    }
+
+    #[test]
+    fn k8s_references() {
+        let finder = make_finder();
+        assert_eq!(
+            span_texts("deleted namespace/prod", &finder),
+            ["namespace", "/", "prod"]
+        );
+        assert_eq!(
+            span_texts("deployment.apps/api restarted", &finder),
+            ["deployment.apps", "/", "api"]
+        );
+        assert_eq!(span_texts("svc/web-frontend", &finder), ["svc", "/", "web-frontend"]);
+    }
+
+    #[test]
+    fn k8s_pod_hash_is_dimmed() {
+        let finder = make_finder();
+        let spans = styled("pod/checkout-7d9f8b6c5d-x2k4q evicted");
+        assert_eq!(
+            spans,
+            [
+                ("pod", finder.kind),
+                ("/", finder.separator),
+                ("checkout", finder.name),
+                ("-7d9f8b6c5d-x2k4q", finder.hash),
+            ]
+        );
+
+        let spans = styled("replicaset.apps/checkout-7d9f8b6c5d");
+        assert_eq!(spans.last().unwrap(), &("-7d9f8b6c5d", finder.hash));
+    }
+
+    #[test]
+    fn k8s_names_without_hash_stay_whole() {
+        let finder = make_finder();
+        assert_eq!(span_texts("pod/web-0", &finder), ["pod", "/", "web-0"]);
+        assert_eq!(span_texts("pod/api-server", &finder), ["pod", "/", "api-server"]);
+    }
+
+    #[test]
+    fn arn_segments() {
+        let finder = make_finder();
+        let spans = styled("assumed arn:aws:iam::123456789012:role/deploy ok");
+        assert_eq!(
+            spans,
+            [
+                ("arn", finder.kind),
+                (":", finder.separator),
+                ("aws", finder.partition),
+                (":", finder.separator),
+                ("iam", finder.service),
+                ("::", finder.separator),
+                ("123456789012", finder.account),
+                (":", finder.separator),
+                ("role", finder.resource),
+                ("/", finder.separator),
+                ("deploy", finder.resource),
+            ]
+        );
+    }
+
+    #[test]
+    fn arn_with_region_and_no_account() {
+        let texts = span_texts("arn:aws:s3:::my-bucket/logs/", &make_finder());
+        assert_eq!(
+            texts,
+            ["arn", ":", "aws", ":", "s3", ":::", "my-bucket", "/", "logs", "/"]
+        );
+        let texts = span_texts("arn:aws-cn:lambda:cn-north-1:123456789012:function:f", &make_finder());
+        assert!(texts.contains(&"cn-north-1"));
+        assert!(texts.contains(&"aws-cn"));
+    }
+
+    #[test]
+    fn gcp_resource_path() {
+        let finder = make_finder();
+        let spans = styled("projects/my-project/zones/europe-west1-b/instances/vm-1");
+        assert_eq!(spans[0], ("projects", finder.kind));
+        assert_eq!(spans[2], ("my-project", finder.name));
+        assert_eq!(spans[4], ("zones", finder.kind));
+        assert_eq!(spans[6], ("europe-west1-b", finder.name));
+        assert_eq!(spans.last().unwrap(), &("vm-1", finder.name));
+    }
+
+    #[test]
+    fn paths_and_prose_do_not_match() {
+        let finder = make_finder();
+        for input in [
+            "/var/run/pods/web",
+            "/home/me/projects/tailspin/src/main.rs",
+            "12 ns/op",
+            "a pod/ without a name",
+            "pod/web/logs",
+            "arn:aws:iam::12345:role/x",
+            "projects/x",
+        ] {
+            assert!(span_texts(input, &finder).is_empty(), "{input}");
+        }
+    }
+}
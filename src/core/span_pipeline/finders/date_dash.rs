@@ -1,5 +1,5 @@
 use super::build_regex;
-use memchr::memchr2;
+use memchr::memchr3;
 use regex::Regex;
 
 use crate::core::config::DateTimeConfig;
@@ -16,7 +16,7 @@ pub(crate) struct DateDashFinder {
 
 impl DateDashFinder {
     pub fn new(config: DateTimeConfig, palette: &mut Palette) -> Self {
-        // Both branches are exactly 10 bytes (4+1+2+1+2), so we can use
+        // All branches are exactly 10 bytes (4+1+2+1+2), so we can use
         // find_iter and compute component offsets arithmetically.
         let pattern = r"(?x)
             # Leading \b only: a trailing one would reject the `T` in ISO-8601
@@ -28,6 +28,9 @@ impl DateDashFinder {
                 |
                 # Branch B: xx-xx-YYYY
                 (?: (?: 0[1-9] | [12]\d | 3[01] ) [-/] (?: 0[1-9] | [12]\d | 3[01] ) [-/] (?: 19\d{2} | 20\d{2} ) )
+                |
+                # Branch C: European DD.MM.YYYY
+                (?: (?: 0[1-9] | [12]\d | 3[01] ) \. (?: 0[1-9] | 1[0-2] ) \. (?: 19\d{2} | 20\d{2} ) )
             )
         ";
 
@@ -43,7 +46,7 @@ impl DateDashFinder {
 
 impl Finder for DateDashFinder {
     fn find_spans(&self, input: &str, collector: &mut Collector) {
-        if memchr2(b'-', b'/', input.as_bytes()).is_none() {
+        if memchr3(b'-', b'/', b'.', input.as_bytes()).is_none() {
             return;
         }
 
@@ -51,9 +54,17 @@ impl Finder for DateDashFinder {
             let s = m.start();
             let bytes = m.as_str().as_bytes();
 
-            // Both branches are exactly 10 bytes. Distinguish by checking
+            // A dotted date inside a longer dotted run is a version or an
+            // address, not a date: `1.17.10.2026`, `17.10.2026.1`.
+            if bytes[2] == b'.'
+                && (input[..s].ends_with('.') || input[m.end()..].starts_with(|c: char| c == '.' || c.is_ascii_digit()))
+            {
+                continue;
+            }
+
+            // All branches are exactly 10 bytes. Distinguish by checking
             // whether position 4 is a separator (Branch A: YYYY-MM-DD)
-            // or position 2 is a separator (Branch B: MM-DD-YYYY).
+            // or position 2 is a separator (Branches B and C: xx-xx-YYYY).
             if bytes[4] == b'-' || bytes[4] == b'/' {
                 // Branch A: YYYY-MM-DD
                 collector.push(s, s + 4, self.date);
@@ -62,7 +73,7 @@ impl Finder for DateDashFinder {
                 collector.push(s + 7, s + 8, self.separator);
                 collector.push(s + 8, s + 10, self.date);
             } else {
-                // Branch B: MM-DD-YYYY
+                // Branches B and C: MM-DD-YYYY, DD.MM.YYYY
                 collector.push(s, s + 2, self.date);
                 collector.push(s + 2, s + 3, self.separator);
                 collector.push(s + 3, s + 5, self.date);
@@ -114,6 +125,20 @@ mod tests {
         assert_eq!(texts, ["09", "/", "09", "/", "2022"]);
     }
 
+    #[test]
+    fn dd_mm_yyyy_with_dots() {
+        let texts = span_texts("due 17.10.2026", &make_finder());
+        assert_eq!(texts, ["17", ".", "10", ".", "2026"]);
+    }
+
+    #[test]
+    fn dotted_runs_no_match() {
+        let finder = make_finder();
+        for input in ["1.17.10.2026", "17.10.2026.1", "17.13.2026", "17.10-2026"] {
+            assert!(span_texts(input, &finder).is_empty(), "{input}");
+        }
+    }
+
     #[test]
     fn invalid_year_no_match() {
         assert!(span_texts("3022-09-09", &make_finder()).is_empty());
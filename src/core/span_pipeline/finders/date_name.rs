@@ -0,0 +1,201 @@
+use super::build_regex;
+use regex::Regex;
+
+use crate::core::config::DateTimeConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+const MONTHS: [&[u8]; 12] = [
+    b"Jan", b"Feb", b"Mar", b"Apr", b"May", b"Jun", b"Jul", b"Aug", b"Sep", b"Oct", b"Nov", b"Dec",
+];
+
+#[derive(Debug, Clone)]
+pub(crate) struct DateNameFinder {
+    regex: Regex,
+    date: StyleId,
+    time: StyleId,
+    zone: StyleId,
+    separator: StyleId,
+}
+
+impl DateNameFinder {
+    pub fn new(config: DateTimeConfig, palette: &mut Palette) -> Self {
+        // Timestamps that spell out the month, which the numeric finders
+        // cannot see. Each branch names its own time and zone groups; the
+        // regex crate does not allow a name twice.
+        let pattern = r"(?x)
+            \b
+            (?:
+                # Common Log Format (nginx, Apache): 17/Oct/2026:12:00:00 +0000
+                (?:0[1-9]|[12]\d|3[01]) / (?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec) / (?:19|20)\d{2}
+                :(?P<clf_time>(?:[01]\d|2[0-3]):[0-5]\d:[0-5]\d)
+                (?:\x20(?P<clf_zone>[+-]\d{4}))?
+              |
+                # HTTP headers and RFC 2822: Fri, 17 Oct 2026 12:00:00 GMT
+                (?:(?:Mon|Tue|Wed|Thu|Fri|Sat|Sun),\x20)?
+                (?:0?[1-9]|[12]\d|3[01]) \x20 (?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec) \x20 (?:19|20)\d{2}
+                (?:
+                    \x20(?P<http_time>(?:[01]\d|2[0-3]):[0-5]\d:[0-5]\d)
+                    (?:\x20(?P<http_zone>GMT|UTC|[+-]\d{4}))?
+                )?
+              |
+                # BSD syslog: Oct 17 12:00:00, with the day space-padded
+                (?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec) \x20 (?:\x20[1-9]|0[1-9]|[12]\d|3[01])
+                \x20(?P<syslog_time>(?:[01]\d|2[0-3]):[0-5]\d:[0-5]\d(?:\.\d+)?)
+            )
+            \b
+        ";
+
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            date: palette.intern(config.date),
+            time: palette.intern(config.time),
+            zone: palette.intern(config.zone),
+            separator: palette.intern(config.separator),
+        }
+    }
+
+    /// Style each digit or letter run of `text` (which starts at `offset`)
+    /// with `style`, and each punctuation byte with the separator style.
+    /// Spaces are left alone.
+    fn push_components(&self, text: &str, offset: usize, style: StyleId, collector: &mut Collector) {
+        let bytes = text.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let start = i;
+            if bytes[i].is_ascii_alphanumeric() {
+                while i < bytes.len() && bytes[i].is_ascii_alphanumeric() {
+                    i += 1;
+                }
+                collector.push(offset + start, offset + i, style);
+            } else {
+                i += 1;
+                if bytes[start] != b' ' {
+                    collector.push(offset + start, offset + i, self.separator);
+                }
+            }
+        }
+    }
+}
+
+impl Finder for DateNameFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        // Every match spells out a month.
+        let month = |w: &[u8]| w[0].is_ascii_uppercase() && MONTHS.contains(&w);
+        if !input.as_bytes().windows(3).any(month) {
+            return;
+        }
+
+        for caps in self.regex.captures_iter(input) {
+            let whole = caps.get(0).unwrap();
+            let time = caps
+                .name("clf_time")
+                .or_else(|| caps.name("http_time"))
+                .or_else(|| caps.name("syslog_time"));
+            let zone = caps.name("clf_zone").or_else(|| caps.name("http_zone"));
+
+            let date_end = time.map_or(whole.end(), |t| t.start());
+            self.push_components(&input[whole.start()..date_end], whole.start(), self.date, collector);
+
+            if let Some(time) = time {
+                self.push_components(time.as_str(), time.start(), self.time, collector);
+            }
+            if let Some(zone) = zone {
+                collector.push(zone.start(), zone.end(), self.zone);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> DateNameFinder {
+        DateNameFinder::new(
+            DateTimeConfig {
+                date: Style::new().fg(Color::Magenta),
+                time: Style::new().fg(Color::Blue),
+                zone: Style::new().fg(Color::Red),
+                separator: Style::new().fg(Color::Yellow),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    fn styled(input: &str) -> Vec<(&str, StyleId)> {
+        let mut collector = Collector::new();
+        make_finder().find_spans(input, &mut collector);
+        collector
+            .into_spans()
+            .iter()
+            .map(|s| (&input[s.start..s.end], s.style))
+            .collect()
+    }
+
+    #[test]
+    fn common_log_format() {
+        let texts = span_texts(
+            r#"10.0.0.1 - - [17/Oct/2026:12:00:00 +0000] "GET / HTTP/1.1""#,
+            &make_finder(),
+        );
+        assert_eq!(
+            texts,
+            ["17", "/", "Oct", "/", "2026", ":", "12", ":", "00", ":", "00", "+0000"]
+        );
+    }
+
+    #[test]
+    fn http_date() {
+        let texts = span_texts("Last-Modified: Fri, 17 Oct 2026 12:00:00 GMT", &make_finder());
+        assert_eq!(
+            texts,
+            ["Fri", ",", "17", "Oct", "2026", "12", ":", "00", ":", "00", "GMT"]
+        );
+    }
+
+    #[test]
+    fn date_without_time() {
+        assert_eq!(span_texts("released 7 Oct 2026", &make_finder()), ["7", "Oct", "2026"]);
+    }
+
+    #[test]
+    fn syslog_timestamp() {
+        let texts = span_texts("Oct 17 12:00:00 bastion sshd[42]: ok", &make_finder());
+        assert_eq!(texts, ["Oct", "17", "12", ":", "00", ":", "00"]);
+        let texts = span_texts("Oct  7 09:15:02.125 host", &make_finder());
+        assert_eq!(texts, ["Oct", "7", "09", ":", "15", ":", "02", ".", "125"]);
+    }
+
+    #[test]
+    fn names_take_the_date_style() {
+        let finder = make_finder();
+        let spans = styled("Fri, 17 Oct 2026 12:00:00 GMT");
+        let style_of = |text: &str| spans.iter().find(|(t, _)| *t == text).unwrap().1;
+        assert_eq!(style_of("Fri"), finder.date);
+        assert_eq!(style_of("Oct"), finder.date);
+        assert_eq!(style_of("12"), finder.time);
+        assert_eq!(style_of("GMT"), finder.zone);
+        assert_eq!(style_of(","), finder.separator);
+    }
+
+    #[test]
+    fn words_that_are_not_dates_do_not_match() {
+        let finder = make_finder();
+        for input in [
+            "October 17 12:00:00",
+            "Oct 17",
+            "Oct 32 12:00:00",
+            "17/Oct/2026",
+            "17 Octopus 2026",
+            "x17 Oct 2026",
+        ] {
+            assert!(span_texts(input, &finder).is_empty(), "{input}");
+        }
+    }
+}
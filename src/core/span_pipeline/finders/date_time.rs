@@ -6,10 +6,12 @@ use crate::core::config::DateTimeConfig;
 
 use super::super::palette::{Palette, StyleId};
 use super::super::span::{Collector, Finder};
+use super::duration::CLOCK_KEYS;
 
 #[derive(Debug, Clone)]
 pub(crate) struct DateTimeFinder {
     regex: Regex,
+    duration_prefix: Regex,
     time: StyleId,
     zone: StyleId,
     separator: StyleId,
@@ -30,8 +32,13 @@ impl DateTimeFinder {
 
         let regex = build_regex(pattern);
 
+        // A clock after a day count or a key such as `took` is a duration,
+        // left for the duration finder.
+        let duration_prefix = build_regex(&format!(r"(?:\b\d+\x20days?,?\x20|\b(?i:{CLOCK_KEYS})[=:]?\x20*)$"));
+
         Self {
             regex,
+            duration_prefix,
             time: palette.intern(config.time),
             zone: palette.intern(config.zone),
             separator: palette.intern(config.separator),
@@ -48,10 +55,15 @@ impl Finder for DateTimeFinder {
         for m in self.regex.find_iter(input) {
             let s = m.start();
             let bytes = m.as_str().as_bytes();
+            let prefix_len = usize::from(!bytes[0].is_ascii_digit());
+            if self.duration_prefix.is_match(&input[..s + prefix_len]) {
+                continue;
+            }
+
             let mut pos = 0;
 
             // Optional T or whitespace prefix
-            if !bytes[0].is_ascii_digit() {
+            if prefix_len == 1 {
                 collector.push(s, s + 1, self.zone);
                 pos = 1;
             }
@@ -168,6 +180,20 @@ mod tests {
         assert!(span_texts("2001:db8::ff00:42:8329", &make_finder()).is_empty());
     }
 
+    #[test]
+    fn duration_clocks_are_left_alone() {
+        let finder = make_finder();
+        for input in [
+            "took 00:01:23.456",
+            "elapsed=12:00:01",
+            "Uptime: 3:02:01",
+            "up 3 days, 4:05:06",
+        ] {
+            assert!(span_texts(input, &finder).is_empty(), "{input}");
+        }
+        assert!(!span_texts("mistook 12:00:01", &finder).is_empty());
+    }
+
     #[test]
     fn no_time_no_match() {
         assert!(span_texts("No time here!", &make_finder()).is_empty());
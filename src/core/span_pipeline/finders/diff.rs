@@ -0,0 +1,225 @@
+use crate::core::config::DiffConfig;
+use crate::style::Style;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+#[derive(Debug, Clone)]
+pub(crate) struct DiffFinder {
+    header: StyleId,
+    hunk: StyleId,
+    added: StyleId,
+    removed: StyleId,
+    changed: StyleId,
+    /// `None` when unstyled, so indented lines stay untouched.
+    context: Option<StyleId>,
+}
+
+impl DiffFinder {
+    pub fn new(config: DiffConfig, palette: &mut Palette) -> Self {
+        Self {
+            header: palette.intern(config.header),
+            hunk: palette.intern(config.hunk),
+            added: palette.intern(config.added),
+            removed: palette.intern(config.removed),
+            changed: palette.intern(config.changed),
+            context: (config.context != Style::default()).then(|| palette.intern(config.context)),
+        }
+    }
+
+    /// The style for a `git diff` / `kubectl diff` line, matched at column 0.
+    fn unified(&self, bytes: &[u8]) -> Option<StyleId> {
+        if bytes.starts_with(b"diff --git ")
+            || bytes.starts_with(b"--- ")
+            || bytes.starts_with(b"+++ ")
+            || is_index_line(bytes)
+        {
+            return Some(self.header);
+        }
+        match bytes.first()? {
+            b'+' => Some(self.added),
+            b'-' => Some(self.removed),
+            b' ' => self.context,
+            _ => None,
+        }
+    }
+
+    /// The style for a `terraform plan` line: an indented action marker
+    /// followed by a space. `-/+` and `+/-` are replacements.
+    fn plan(&self, bytes: &[u8]) -> Option<StyleId> {
+        let indent = bytes.iter().take_while(|&&b| b == b' ').count();
+        let rest = &bytes[indent..];
+        let (marker, style) = if rest.starts_with(b"-/+") || rest.starts_with(b"+/-") {
+            (3, self.changed)
+        } else {
+            match rest.first()? {
+                b'+' => (1, self.added),
+                b'-' => (1, self.removed),
+                b'~' => (1, self.changed),
+                _ => return None,
+            }
+        };
+        (rest.get(marker) == Some(&b' ')).then_some(style)
+    }
+}
+
+/// `index 3b18e51..a4c2f9e 100644`: two abbreviated object ids.
+fn is_index_line(bytes: &[u8]) -> bool {
+    let Some(rest) = bytes.strip_prefix(b"index ") else {
+        return false;
+    };
+    let ids = rest.split(|&b| b == b' ').next().unwrap_or_default();
+    let mut parts = ids.splitn(2, |&b| b == b'.');
+    let (Some(old), Some(new)) = (parts.next(), parts.next().and_then(|p| p.strip_prefix(b"."))) else {
+        return false;
+    };
+    let is_id = |id: &[u8]| id.len() >= 7 && id.iter().all(u8::is_ascii_hexdigit);
+    is_id(old) && is_id(new)
+}
+
+/// The length of the `@@ -12,7 +12,8 @@` range header opening a hunk.
+fn hunk_header_len(bytes: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    let expect = |token: &[u8], i: &mut usize| bytes[*i..].starts_with(token).then(|| *i += token.len());
+    let range = |i: &mut usize| {
+        let digits = |i: &mut usize| {
+            let len = bytes[*i..].iter().take_while(|b| b.is_ascii_digit()).count();
+            *i += len;
+            (len > 0).then_some(())
+        };
+        digits(i)?;
+        if bytes.get(*i) == Some(&b',') {
+            *i += 1;
+            digits(i)?;
+        }
+        Some(())
+    };
+
+    expect(b"@@ -", &mut i)?;
+    range(&mut i)?;
+    expect(b" +", &mut i)?;
+    range(&mut i)?;
+    expect(b" @@", &mut i)?;
+    Some(i)
+}
+
+impl Finder for DiffFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+
+        if let Some(len) = hunk_header_len(bytes) {
+            collector.push(0, len, self.hunk);
+            return;
+        }
+
+        // Line-wide styles are fallbacks so paths, numbers and the like
+        // inside a changed line keep their own highlighting.
+        if let Some(style) = self.plan(bytes).or_else(|| self.unified(bytes)) {
+            collector.push_fallback(0, bytes.len(), style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    fn make_finder() -> DiffFinder {
+        DiffFinder::new(
+            DiffConfig {
+                header: Style::new().bold(),
+                hunk: Style::new().fg(Color::Cyan),
+                added: Style::new().fg(Color::Green),
+                removed: Style::new().fg(Color::Red),
+                changed: Style::new().fg(Color::Yellow),
+                context: Style::new().faint(),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    fn styled(input: &str) -> Vec<(&str, StyleId)> {
+        let mut collector = Collector::new();
+        make_finder().find_spans(input, &mut collector);
+        collector
+            .into_spans()
+            .iter()
+            .map(|s| (&input[s.start..s.end], s.style))
+            .collect()
+    }
+
+    #[test]
+    fn headers() {
+        let finder = make_finder();
+        for line in [
+            "diff --git a/src/main.rs b/src/main.rs",
+            "index 3b18e51..a4c2f9e 100644",
+            "--- a/src/main.rs",
+            "+++ b/src/main.rs",
+        ] {
+            assert_eq!(styled(line), [(line, finder.header)], "{line}");
+        }
+    }
+
+    #[test]
+    fn hunk_header_styles_only_the_ranges() {
+        let finder = make_finder();
+        assert_eq!(
+            styled("@@ -12,7 +12,8 @@ fn main() {"),
+            [("@@ -12,7 +12,8 @@", finder.hunk)]
+        );
+        assert_eq!(styled("@@ -1 +1 @@"), [("@@ -1 +1 @@", finder.hunk)]);
+        assert!(styled("@@ -x,7 +12,8 @@").is_empty());
+    }
+
+    #[test]
+    fn unified_lines() {
+        let finder = make_finder();
+        assert_eq!(styled("+    let x = 1;"), [("+    let x = 1;", finder.added)]);
+        assert_eq!(styled("-    let x = 0;"), [("-    let x = 0;", finder.removed)]);
+        assert_eq!(
+            styled("     println!();"),
+            [("     println!();", finder.context.unwrap())]
+        );
+    }
+
+    #[test]
+    fn unstyled_context_lines_are_not_pushed() {
+        let finder = DiffFinder::new(DiffConfig::default(), &mut Palette::new());
+        let mut collector = Collector::new();
+        finder.find_spans("    at handler (/app/src/server.js:10:15)", &mut collector);
+        assert!(collector.into_spans().is_empty());
+    }
+
+    #[test]
+    fn terraform_plan_markers() {
+        let finder = make_finder();
+        assert_eq!(
+            styled("  + resource \"aws_s3_bucket\" \"logs\" {"),
+            [("  + resource \"aws_s3_bucket\" \"logs\" {", finder.added)]
+        );
+        assert_eq!(
+            styled("      ~ instance_type = \"t3.micro\" -> \"t3.small\""),
+            [("      ~ instance_type = \"t3.micro\" -> \"t3.small\"", finder.changed)]
+        );
+        assert_eq!(
+            styled("-/+ resource \"aws_instance\" \"web\" {"),
+            [("-/+ resource \"aws_instance\" \"web\" {", finder.changed)]
+        );
+        assert_eq!(styled("    - tags = {}"), [("    - tags = {}", finder.removed)]);
+    }
+
+    #[test]
+    fn ordinary_lines_are_left_alone() {
+        for line in [
+            "2024-01-01 INFO started",
+            "index.html served",
+            "index 3b18..a4c2 short ids",
+            "@@ not a hunk",
+            "~/bin is on PATH",
+        ] {
+            assert!(styled(line).is_empty(), "{line}");
+        }
+    }
+}
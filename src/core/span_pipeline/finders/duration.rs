@@ -6,6 +6,11 @@ use crate::core::config::DurationConfig;
 use super::super::palette::{Palette, StyleId};
 use super::super::span::{Collector, Finder};
 
+/// Keys after which a `H:MM:SS` clock is a duration rather than a time of
+/// day: `took 00:01:23.456`, `uptime=3:02:01`. Shared with the date finder,
+/// which leaves these clocks alone.
+pub(super) const CLOCK_KEYS: &str = "elapsed|took|duration|uptime|runtime";
+
 #[derive(Debug, Clone)]
 pub(crate) struct DurationFinder {
     regex: Regex,
@@ -16,15 +21,29 @@ pub(crate) struct DurationFinder {
 
 impl DurationFinder {
     pub fn new(config: DurationConfig, palette: &mut Palette) -> Self {
-        let pattern = r"(?x)
-            \b
-            (?P<integer>\d+)                    # integer part
-            (?:(?P<dot>\.)(?P<fraction>\d+))?   # optional fractional part
-            (?P<unit>ns|us|ms|s|m|h)            # time unit
-            \b
-        ";
-
-        let regex = build_regex(pattern);
+        // A bare `H:MM:SS` is a time of day; clocks only count as durations
+        // after a day count or a key such as `took`, and only the clock part
+        // (the `clock` group) is styled.
+        let pattern = format!(
+            r"(?x)
+            \b(?:
+                # 150ms, 2.5s, and Go's compound 1h30m15s
+                (?:\d+(?:\.\d+)?(?:ns|us|ms|s|m|h))+
+              # ISO 8601: P3DT4H, PT1H30M, P1Y2M
+              | P(?:
+                    (?:\d+[YMWD])+(?:T(?:\d+(?:[.,]\d+)?[HMS])+)?
+                  | T(?:\d+(?:[.,]\d+)?[HMS])+
+                )
+              # 3 days, 4:05:06
+              | \d+\x20days?,?\x20\d{{1,2}}:\d{{2}}:\d{{2}}(?:\.\d+)?
+              # took 00:01:23.456
+              | (?i:{CLOCK_KEYS})[=:]?\x20*
+                (?P<clock>\d+:\d{{2}}:\d{{2}}(?:\.\d+)?)
+            )\b
+        "
+        );
+
+        let regex = build_regex(&pattern);
 
         Self {
             regex,
@@ -37,24 +56,43 @@ impl DurationFinder {
 
 impl Finder for DurationFinder {
     fn find_spans(&self, input: &str, collector: &mut Collector) {
-        // Every match contains a digit immediately followed by its unit's first letter.
-        let digit_then_unit = |w: &[u8]| w[0].is_ascii_digit() && matches!(w[1], b'n' | b'u' | b'm' | b's' | b'h');
+        // Every match contains a digit immediately followed by a unit or
+        // designator letter, or by a clock's `:`.
+        let digit_then_unit = |w: &[u8]| w[0].is_ascii_digit() && (w[1].is_ascii_alphabetic() || w[1] == b':');
         if !input.as_bytes().windows(2).any(digit_then_unit) {
             return;
         }
 
         for caps in self.regex.captures_iter(input) {
-            let integer = caps.name("integer").unwrap();
-            collector.push(integer.start(), integer.end(), self.value);
-
-            if let Some(dot) = caps.name("dot") {
-                let fraction = caps.name("fraction").unwrap();
-                collector.push(dot.start(), dot.end(), self.separator);
-                collector.push(fraction.start(), fraction.end(), self.value);
-            }
+            let m = caps.name("clock").unwrap_or_else(|| caps.get(0).unwrap());
+            self.push_components(m.as_str().as_bytes(), m.start(), collector);
+        }
+    }
+}
 
-            let unit = caps.name("unit").unwrap();
-            collector.push(unit.start(), unit.end(), self.unit);
+impl DurationFinder {
+    /// Styles each component of a matched duration: digit runs as values,
+    /// letter runs (`ms`, `days`, ISO `P`/`T`/`H`) as units, and the `.`, `,`
+    /// or `:` between them as separators.
+    fn push_components(&self, bytes: &[u8], offset: usize, collector: &mut Collector) {
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            let (len, style) = if b.is_ascii_digit() {
+                (bytes[i..].iter().take_while(|c| c.is_ascii_digit()).count(), self.value)
+            } else if b.is_ascii_alphabetic() {
+                (
+                    bytes[i..].iter().take_while(|c| c.is_ascii_alphabetic()).count(),
+                    self.unit,
+                )
+            } else if matches!(b, b'.' | b',' | b':') {
+                (1, self.separator)
+            } else {
+                i += 1;
+                continue;
+            };
+            collector.push(offset + i, offset + i + len, style);
+            i += len;
         }
     }
 }
@@ -94,8 +132,48 @@ mod tests {
     }
 
     #[test]
-    fn compound_durations_do_not_match() {
-        assert!(span_texts("waited 1h30m", &make_finder()).is_empty());
+    fn go_compound_durations() {
+        let texts = span_texts("waited 1h30m15s then 2m0.5s", &make_finder());
+        assert_eq!(texts, ["1", "h", "30", "m", "15", "s", "2", "m", "0", ".", "5", "s"]);
+    }
+
+    #[test]
+    fn iso_8601_durations() {
+        assert_eq!(
+            span_texts("timeout PT1H30M", &make_finder()),
+            ["PT", "1", "H", "30", "M"]
+        );
+        assert_eq!(
+            span_texts("retention P3DT4H", &make_finder()),
+            ["P", "3", "DT", "4", "H"]
+        );
+        assert_eq!(span_texts("PT0,5S", &make_finder()), ["PT", "0", ",", "5", "S"]);
+        assert!(span_texts("PT or P or PTX", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn clock_durations_after_a_key_or_day_count() {
+        assert_eq!(
+            span_texts("took 00:01:23.456", &make_finder()),
+            ["00", ":", "01", ":", "23", ".", "456"]
+        );
+        assert_eq!(
+            span_texts("elapsed=12:00:01", &make_finder()),
+            ["12", ":", "00", ":", "01"]
+        );
+        assert_eq!(
+            span_texts("up 3 days, 4:05:06", &make_finder()),
+            ["3", "days", ",", "4", ":", "05", ":", "06"]
+        );
+        assert_eq!(
+            span_texts("1 day 02:00:00", &make_finder()),
+            ["1", "day", "02", ":", "00", ":", "00"]
+        );
+    }
+
+    #[test]
+    fn bare_clocks_are_times_of_day() {
+        assert!(span_texts("at 12:00:01 the job ran", &make_finder()).is_empty());
     }
 
     #[test]
@@ -0,0 +1,283 @@
+use crate::core::config::EpochConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// 2000-01-01T00:00:00Z, which also rules out zero-padded counters such as
+/// `0000000001`.
+const EARLIEST: u64 = 946_684_800;
+
+/// 2100-01-01T00:00:00Z.
+const LATEST: u64 = 4_102_444_800;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+#[derive(Debug, Clone)]
+pub(crate) struct EpochFinder {
+    style: StyleId,
+    annotation: Option<StyleId>,
+    key_value: bool,
+    json: bool,
+    anywhere: bool,
+}
+
+impl EpochFinder {
+    pub fn new(config: EpochConfig, palette: &mut Palette) -> Self {
+        Self {
+            style: palette.intern(config.style),
+            annotation: config.annotate.then(|| palette.intern(config.annotation)),
+            key_value: config.key_value,
+            json: config.json,
+            anywhere: config.anywhere,
+        }
+    }
+
+    /// Whether the value starting at `start` sits in a context the config
+    /// accepts: after `key=`, as a JSON value (`"key": 1760702400`), or
+    /// anywhere.
+    fn in_context(&self, bytes: &[u8], start: usize) -> bool {
+        let before = &bytes[..start];
+        if self.anywhere {
+            return before.last().is_none_or(|&b| !is_word_byte(b) && b != b'.');
+        }
+
+        let is_key_value = before
+            .strip_suffix(b"=")
+            .and_then(<[u8]>::last)
+            .is_some_and(|&b| is_word_byte(b));
+        let is_json = before
+            .trim_ascii_end()
+            .strip_suffix(b":")
+            .is_some_and(|key| key.trim_ascii_end().ends_with(b"\""));
+
+        (self.key_value && is_key_value) || (self.json && is_json)
+    }
+}
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+/// The length of the digit run at the start of `bytes`.
+fn digits(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|b| b.is_ascii_digit()).count()
+}
+
+/// Seconds, milliseconds, microseconds or nanoseconds since the Unix epoch:
+/// the whole seconds are always the first ten digits, and the rest is the
+/// fraction. Seconds may also carry a decimal fraction (`1760702400.25`).
+/// Returns the value's length, its seconds and fraction digits.
+fn parse_epoch(bytes: &[u8]) -> Option<(usize, u64, &[u8])> {
+    let len = digits(bytes);
+    let (len, fraction) = match len {
+        10 if bytes.get(10) == Some(&b'.') => {
+            let fraction = digits(&bytes[11..]);
+            if !(1..=9).contains(&fraction) {
+                return None;
+            }
+            (11 + fraction, &bytes[11..11 + fraction])
+        }
+        10 | 13 | 16 | 19 => (len, &bytes[10..len]),
+        _ => return None,
+    };
+    if bytes.get(len).is_some_and(|&b| is_word_byte(b) || b == b'.') {
+        return None;
+    }
+
+    let seconds = std::str::from_utf8(&bytes[..10]).ok()?.parse::<u64>().ok()?;
+    (EARLIEST..LATEST)
+        .contains(&seconds)
+        .then_some((len, seconds, fraction))
+}
+
+/// `2025-10-17T12:00:00.123Z` for the given seconds and fraction digits.
+fn format_iso8601(seconds: u64, fraction: &[u8]) -> String {
+    let (year, month, day) = civil_from_days(seconds / SECONDS_PER_DAY);
+    let time = seconds % SECONDS_PER_DAY;
+    let mut iso = format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    );
+    if !fraction.is_empty() {
+        iso.push('.');
+        iso.extend(fraction.iter().map(|&b| char::from(b)));
+    }
+    iso.push('Z');
+    iso
+}
+
+/// The proleptic Gregorian date `days` after 1970-01-01, after Howard
+/// Hinnant's `civil_from_days`. Only non-negative days reach it.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+impl Finder for EpochFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if !(self.key_value || self.json || self.anywhere) {
+            return;
+        }
+
+        let bytes = input.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+
+            let parsed = if i > 0 && bytes[i - 1].is_ascii_digit() {
+                None
+            } else {
+                parse_epoch(&bytes[i..]).filter(|_| self.in_context(bytes, i))
+            };
+            let Some((len, seconds, fraction)) = parsed else {
+                i += digits(&bytes[i..]);
+                continue;
+            };
+
+            match self.annotation {
+                Some(annotation) => {
+                    let text = format!(" ({})", format_iso8601(seconds, fraction));
+                    collector.push_annotated(i, i + len, self.style, text, annotation);
+                }
+                None => collector.push(i, i + len, self.style),
+            }
+            i += len;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn config() -> EpochConfig {
+        EpochConfig {
+            style: Style::new().fg(Color::Magenta),
+            annotation: Style::new().faint(),
+            key_value: true,
+            json: true,
+            anywhere: false,
+            annotate: false,
+        }
+    }
+
+    fn make_finder() -> EpochFinder {
+        EpochFinder::new(config(), &mut Palette::new())
+    }
+
+    fn annotations(finder: &EpochFinder, input: &str) -> Vec<String> {
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+        collector.into_annotations().into_iter().map(|a| a.text).collect()
+    }
+
+    #[test]
+    fn key_value_seconds() {
+        assert_eq!(span_texts("ts=1760702400 ok", &make_finder()), ["1760702400"]);
+    }
+
+    #[test]
+    fn json_milliseconds() {
+        let texts = span_texts(r#"{"time":1760702400123,"level":"info"}"#, &make_finder());
+        assert_eq!(texts, ["1760702400123"]);
+        let texts = span_texts(r#"{"time": 1760702400123}"#, &make_finder());
+        assert_eq!(texts, ["1760702400123"]);
+    }
+
+    #[test]
+    fn micro_nano_and_fractional_seconds() {
+        let finder = make_finder();
+        assert_eq!(span_texts("t=1760702400123456", &finder), ["1760702400123456"]);
+        assert_eq!(span_texts("t=1760702400123456789", &finder), ["1760702400123456789"]);
+        assert_eq!(span_texts("t=1760702400.25", &finder), ["1760702400.25"]);
+    }
+
+    #[test]
+    fn other_lengths_and_contexts_do_not_match() {
+        let finder = make_finder();
+        for input in [
+            "id=17607024001",
+            "id=176070240012",
+            "took 1760702400 cycles",
+            "ts=1760702400abc",
+            "ts=1760702400.",
+            "path=/1760702400",
+        ] {
+            assert!(span_texts(input, &finder).is_empty(), "{input}");
+        }
+    }
+
+    #[test]
+    fn implausible_dates_do_not_match() {
+        let finder = make_finder();
+        assert!(span_texts("n=0000000001", &finder).is_empty());
+        assert!(span_texts("n=9999999999", &finder).is_empty());
+    }
+
+    #[test]
+    fn contexts_are_configurable() {
+        let anywhere = EpochFinder::new(
+            EpochConfig {
+                anywhere: true,
+                ..config()
+            },
+            &mut Palette::new(),
+        );
+        assert_eq!(span_texts("took 1760702400 cycles", &anywhere), ["1760702400"]);
+        assert!(span_texts("v1.1760702400", &anywhere).is_empty());
+
+        let json_only = EpochFinder::new(
+            EpochConfig {
+                key_value: false,
+                ..config()
+            },
+            &mut Palette::new(),
+        );
+        assert!(span_texts("ts=1760702400", &json_only).is_empty());
+        assert_eq!(span_texts(r#""ts":1760702400"#, &json_only), ["1760702400"]);
+    }
+
+    #[test]
+    fn annotate_appends_the_decoded_time() {
+        let finder = EpochFinder::new(
+            EpochConfig {
+                annotate: true,
+                ..config()
+            },
+            &mut Palette::new(),
+        );
+        assert_eq!(annotations(&finder, "ts=1760702400"), [" (2025-10-17T12:00:00Z)"]);
+        assert_eq!(
+            annotations(&finder, r#""time":1760702400123"#),
+            [" (2025-10-17T12:00:00.123Z)"]
+        );
+        assert_eq!(annotations(&finder, "ts=1709164800.5"), [" (2024-02-29T00:00:00.5Z)"]);
+        assert!(annotations(&make_finder(), "ts=1760702400").is_empty());
+    }
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(47_481), (2099, 12, 31));
+    }
+}
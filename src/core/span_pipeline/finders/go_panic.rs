@@ -0,0 +1,305 @@
+use super::build_regex;
+use memchr::memchr;
+use regex::Regex;
+
+use crate::core::config::GoPanicConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+#[derive(Debug, Clone)]
+pub(crate) struct GoPanicFinder {
+    marker_regex: Regex,
+    goroutine_regex: Regex,
+    function_regex: Regex,
+    location_regex: Regex,
+    marker: StyleId,
+    frame: StyleId,
+    goroutine_id: StyleId,
+    state: StyleId,
+    package: StyleId,
+    function: StyleId,
+    file: StyleId,
+    line_number: StyleId,
+    number: StyleId,
+    letter: StyleId,
+    x: StyleId,
+}
+
+impl GoPanicFinder {
+    pub fn new(config: GoPanicConfig, palette: &mut Palette) -> Self {
+        let marker_pattern = r"(?m)^(?P<marker>panic:|fatal error:)\x20";
+        let marker_regex = build_regex(marker_pattern);
+
+        // `goroutine 17 [running]:` or `goroutine 4 [chan receive, 5 minutes]:`
+        let goroutine_pattern = r"(?xm)
+            ^
+            (?P<keyword>goroutine\x20)
+            (?P<id>\d+)
+            (?P<open>\x20\[)
+            (?P<state>(?u:[^\]\n])+)
+            (?P<close>\]:)
+        ";
+        let goroutine_regex = build_regex(goroutine_pattern);
+
+        // Function lines start at column 0: the import path up to the package
+        // name, then the (possibly method or closure) function. `[...]` stands
+        // in for elided generic type arguments.
+        let function_pattern = r"(?xm)
+            ^
+            (?:(?P<created>created\x20by)\x20)?
+            (?P<package>(?:[A-Za-z0-9_.~-]+/)*[A-Za-z0-9_-]+\.)
+            (?P<function>
+                (?:\(\*?[A-Za-z0-9_]+(?:\[\.\.\.\])?\)\.)?
+                [A-Za-z0-9_]+(?:\[\.\.\.\])?
+                (?:\.[A-Za-z0-9_]+)*
+            )
+            (?:
+                (?P<open>\()(?P<args>(?u:[^)\n])*)(?P<close>\))
+              | (?P<in_goroutine>\x20in\x20goroutine\x20)(?P<parent>\d+)
+            )?
+            [\x20\t]*$
+        ";
+        let function_regex = build_regex(function_pattern);
+
+        let location_pattern = r"(?xm)
+            ^\s+
+            (?P<file>(?u:[^\s:])+\.(?:go|s))
+            (?P<colon>:)
+            (?P<line>\d+)
+            (?:(?P<plus>\x20\+)(?P<offset>0x[0-9a-fA-F]+))?
+            [\x20\t]*$
+        ";
+        let location_regex = build_regex(location_pattern);
+
+        Self {
+            marker_regex,
+            goroutine_regex,
+            function_regex,
+            location_regex,
+            marker: palette.intern(config.marker),
+            frame: palette.intern(config.frame),
+            goroutine_id: palette.intern(config.goroutine_id),
+            state: palette.intern(config.state),
+            package: palette.intern(config.package),
+            function: palette.intern(config.function),
+            file: palette.intern(config.file),
+            line_number: palette.intern(config.line_number),
+            number: palette.intern(config.number),
+            letter: palette.intern(config.letter),
+            x: palette.intern(config.x),
+        }
+    }
+
+    /// Styles every `0x…` token in `text` the way `PointerFinder` styles a pointer.
+    fn push_hex_tokens(&self, text: &str, offset: usize, collector: &mut Collector) {
+        let bytes = text.as_bytes();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let starts_token = bytes[i] == b'0'
+                && bytes.get(i + 1) == Some(&b'x')
+                && bytes.get(i + 2).is_some_and(u8::is_ascii_hexdigit)
+                && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+            if !starts_token {
+                i += 1;
+                continue;
+            }
+
+            collector.push(offset + i, offset + i + 1, self.number);
+            collector.push(offset + i + 1, offset + i + 2, self.x);
+            i += 2;
+            while i < bytes.len() && bytes[i].is_ascii_hexdigit() {
+                let style = if bytes[i].is_ascii_digit() {
+                    self.number
+                } else {
+                    self.letter
+                };
+                collector.push(offset + i, offset + i + 1, style);
+                i += 1;
+            }
+        }
+    }
+}
+
+impl Finder for GoPanicFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+
+        if memchr(b':', bytes).is_some() {
+            for caps in self.marker_regex.captures_iter(input) {
+                let marker = caps.name("marker").unwrap();
+                collector.push(marker.start(), marker.end(), self.marker);
+            }
+        }
+
+        if input.contains("goroutine ") {
+            for caps in self.goroutine_regex.captures_iter(input) {
+                let keyword = caps.name("keyword").unwrap();
+                let id = caps.name("id").unwrap();
+                let open = caps.name("open").unwrap();
+                let state = caps.name("state").unwrap();
+                let close = caps.name("close").unwrap();
+                collector.push(keyword.start(), keyword.end(), self.frame);
+                collector.push(id.start(), id.end(), self.goroutine_id);
+                collector.push(open.start(), open.end(), self.frame);
+                collector.push(state.start(), state.end(), self.state);
+                collector.push(close.start(), close.end(), self.frame);
+            }
+        }
+
+        if memchr(b'.', bytes).is_none() {
+            return;
+        }
+
+        for caps in self.function_regex.captures_iter(input) {
+            let created = caps.name("created");
+            let open = caps.name("open");
+            // Without `created by` or an argument list, `pkg.Name` at column 0
+            // is just as likely to be a file name or prose.
+            if created.is_none() && open.is_none() {
+                continue;
+            }
+
+            if let Some(created) = created {
+                collector.push(created.start(), created.end(), self.marker);
+            }
+            let package = caps.name("package").unwrap();
+            let function = caps.name("function").unwrap();
+            collector.push(package.start(), package.end(), self.package);
+            collector.push(function.start(), function.end(), self.function);
+
+            if let (Some(open), Some(args), Some(close)) = (open, caps.name("args"), caps.name("close")) {
+                collector.push(open.start(), open.end(), self.frame);
+                self.push_hex_tokens(args.as_str(), args.start(), collector);
+                collector.push(close.start(), close.end(), self.frame);
+            }
+            if let (Some(in_goroutine), Some(parent)) = (caps.name("in_goroutine"), caps.name("parent")) {
+                collector.push(in_goroutine.start(), in_goroutine.end(), self.frame);
+                collector.push(parent.start(), parent.end(), self.goroutine_id);
+            }
+        }
+
+        for caps in self.location_regex.captures_iter(input) {
+            let file = caps.name("file").unwrap();
+            let colon = caps.name("colon").unwrap();
+            let line = caps.name("line").unwrap();
+            collector.push(file.start(), file.end(), self.file);
+            collector.push(colon.start(), colon.end(), self.frame);
+            collector.push(line.start(), line.end(), self.line_number);
+
+            if let (Some(plus), Some(offset)) = (caps.name("plus"), caps.name("offset")) {
+                collector.push(plus.start() + 1, plus.end(), self.frame);
+                self.push_hex_tokens(offset.as_str(), offset.start(), collector);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> GoPanicFinder {
+        GoPanicFinder::new(
+            GoPanicConfig {
+                marker: Style::new().fg(Color::Red).bold(),
+                frame: Style::new().faint(),
+                goroutine_id: Style::new().fg(Color::Cyan),
+                state: Style::new().fg(Color::Yellow),
+                package: Style::new().fg(Color::Green).faint(),
+                function: Style::new().fg(Color::Green),
+                file: Style::new().fg(Color::Yellow),
+                line_number: Style::new().fg(Color::Cyan),
+                number: Style::new().fg(Color::Blue),
+                letter: Style::new().fg(Color::Magenta),
+                x: Style::new().fg(Color::Red),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn panic_marker() {
+        let texts = span_texts(
+            "panic: runtime error: index out of range [5] with length 3",
+            &make_finder(),
+        );
+        assert_eq!(texts, ["panic:"]);
+    }
+
+    #[test]
+    fn goroutine_header_splits_id_and_state() {
+        let texts = span_texts("goroutine 17 [chan receive, 5 minutes]:", &make_finder());
+        assert_eq!(texts, ["goroutine ", "17", " [", "chan receive, 5 minutes", "]:"]);
+    }
+
+    #[test]
+    fn function_line_splits_package_function_and_hex_args() {
+        // Adjacent digits share a style and merge into one span.
+        let texts = span_texts("main.(*Server).handle(0xc0001a, {0x5, 0x5})", &make_finder());
+        assert_eq!(
+            texts,
+            [
+                "main.",
+                "(*Server).handle",
+                "(",
+                "0",
+                "x",
+                "c",
+                "0001",
+                "a",
+                "0",
+                "x",
+                "5",
+                "0",
+                "x",
+                "5",
+                ")"
+            ]
+        );
+    }
+
+    #[test]
+    fn function_line_with_import_path_and_generics() {
+        let texts = span_texts("github.com/acme/api/store.Get[...](...)", &make_finder());
+        assert_eq!(texts, ["github.com/acme/api/store.", "Get[...]", "(", ")"]);
+    }
+
+    #[test]
+    fn location_line_with_pc_offset() {
+        let finder = make_finder();
+        let input = "\t/src/pkg/file.go:123 +0x1d";
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+        let spans = collector.into_spans();
+        let texts: Vec<&str> = spans.iter().map(|s| &input[s.start..s.end]).collect();
+
+        assert_eq!(texts, ["/src/pkg/file.go", ":", "123", "+", "0", "x", "1", "d"]);
+        assert_eq!(spans[4].style, finder.number);
+        assert_eq!(spans[5].style, finder.x);
+        assert_eq!(spans[7].style, finder.letter);
+    }
+
+    #[test]
+    fn created_by_line_with_parent_goroutine() {
+        let texts = span_texts("created by net/http.(*Server).Serve in goroutine 1", &make_finder());
+        assert_eq!(
+            texts,
+            ["created by", "net/http.", "(*Server).Serve", " in goroutine ", "1"]
+        );
+    }
+
+    #[test]
+    fn file_names_and_indented_calls_are_not_frames() {
+        assert!(span_texts("main.go", &make_finder()).is_empty());
+        assert!(span_texts("  calling pkg.Do(x)", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn panic_must_start_the_line() {
+        assert!(span_texts("recovered from panic: boom", &make_finder()).is_empty());
+    }
+}
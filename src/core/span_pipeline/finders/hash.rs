@@ -0,0 +1,206 @@
+use super::build_regex;
+use regex::Regex;
+
+use crate::core::config::HashConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// The shortest hash any branch accepts: an abbreviated git SHA.
+const MIN_HASH_LEN: usize = 7;
+
+#[derive(Debug, Clone)]
+pub(crate) struct HashFinder {
+    regex: Regex,
+    algorithm: StyleId,
+    separator: StyleId,
+    number: StyleId,
+    letter: StyleId,
+}
+
+impl HashFinder {
+    pub fn new(config: HashConfig, palette: &mut Palette) -> Self {
+        // Short hex runs are only hashes in context: after an algorithm prefix
+        // or a hash-ish key. Bare runs must be a full SHA-1 (40) or SHA-256
+        // (64, also the length of a container ID) to count.
+        let pattern = r"(?x)
+            \b
+            (?:
+                (?P<algo>md5|sha1|sha224|sha256|sha384|sha512)
+                (?P<sep>:)
+                (?P<digest>[0-9a-fA-F]{32,128})
+              |
+                (?i:commit|rev(?:ision)?|sha|hash|digest|checksum|git[_-]?(?:sha|commit|rev)|(?:container|image)[_-]?id)
+                (?:\s+|\s*[=:]\s*)
+                (?P<context>[0-9a-fA-F]{7,64})
+              |
+                (?P<bare>[0-9a-fA-F]{64}|[0-9a-fA-F]{40})
+            )
+            \b
+        ";
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            algorithm: palette.intern(config.algorithm),
+            separator: palette.intern(config.separator),
+            number: palette.intern(config.number),
+            letter: palette.intern(config.letter),
+        }
+    }
+
+    fn push_hex(&self, hex: &str, offset: usize, collector: &mut Collector) {
+        for (i, &b) in hex.as_bytes().iter().enumerate() {
+            let style = if b.is_ascii_digit() { self.number } else { self.letter };
+            collector.push(offset + i, offset + i + 1, style);
+        }
+    }
+}
+
+/// Digest length in hex characters for each recognized algorithm prefix.
+fn digest_len(algorithm: &str) -> usize {
+    match algorithm {
+        "md5" => 32,
+        "sha1" => 40,
+        "sha224" => 56,
+        "sha256" => 64,
+        "sha384" => 96,
+        _ => 128,
+    }
+}
+
+/// Whether `input` has a run of at least [`MIN_HASH_LEN`] hex digits.
+fn has_hex_run(input: &str) -> bool {
+    let mut run = 0;
+    for b in input.bytes() {
+        if b.is_ascii_hexdigit() {
+            run += 1;
+            if run >= MIN_HASH_LEN {
+                return true;
+            }
+        } else {
+            run = 0;
+        }
+    }
+    false
+}
+
+impl Finder for HashFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if !has_hex_run(input) {
+            return;
+        }
+
+        for caps in self.regex.captures_iter(input) {
+            if let Some(digest) = caps.name("digest") {
+                let algo = caps.name("algo").unwrap();
+                if digest.len() != digest_len(algo.as_str()) {
+                    continue;
+                }
+
+                // `image@sha256:…` — the `@` belongs to the digest reference.
+                if algo.start() > 0 && input.as_bytes()[algo.start() - 1] == b'@' {
+                    collector.push(algo.start() - 1, algo.start(), self.separator);
+                }
+                let sep = caps.name("sep").unwrap();
+                collector.push(algo.start(), algo.end(), self.algorithm);
+                collector.push(sep.start(), sep.end(), self.separator);
+                self.push_hex(digest.as_str(), digest.start(), collector);
+                continue;
+            }
+
+            // Without a prefix, an all-digit run is a number, not a hash.
+            let hex = caps.name("context").or_else(|| caps.name("bare")).unwrap();
+            if hex.as_str().bytes().any(|b| b.is_ascii_alphabetic()) {
+                self.push_hex(hex.as_str(), hex.start(), collector);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> HashFinder {
+        HashFinder::new(
+            HashConfig {
+                algorithm: Style::new().fg(Color::Green),
+                separator: Style::new().fg(Color::Red),
+                number: Style::new().fg(Color::Blue),
+                letter: Style::new().fg(Color::Magenta),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    /// The full range each hash covers, from its first span to its last.
+    fn hashes(input: &str) -> Vec<&str> {
+        let mut collector = Collector::new();
+        make_finder().find_spans(input, &mut collector);
+        let spans = collector.into_spans();
+
+        let mut ranges: Vec<(usize, usize)> = Vec::new();
+        for span in spans {
+            match ranges.last_mut() {
+                Some(last) if last.1 == span.start => last.1 = span.end,
+                _ => ranges.push((span.start, span.end)),
+            }
+        }
+        ranges.iter().map(|&(s, e)| &input[s..e]).collect()
+    }
+
+    const SHA256: &str = "9b2c1f0e4d7a8b3c6e5f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d";
+
+    #[test]
+    fn sha256_digest_splits_algorithm_separator_and_hex() {
+        let input = format!("pulled sha256:{SHA256}");
+        let texts = span_texts(&input, &make_finder());
+        assert_eq!(&texts[..4], ["sha256", ":", "9", "b"]);
+    }
+
+    #[test]
+    fn image_digest_reference_includes_the_at_sign() {
+        let input = format!("ghcr.io/acme/api@sha256:{SHA256}");
+        assert_eq!(hashes(&input), [format!("@sha256:{SHA256}")]);
+    }
+
+    #[test]
+    fn digest_length_must_match_the_algorithm() {
+        assert!(span_texts("sha256:9b2c1f0e4d7a8b3c6e5f1a2b3c4d5e6f", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn short_sha_after_commit_keyword() {
+        assert_eq!(hashes("HEAD at commit 3f2a9c1 (main)"), ["3f2a9c1"]);
+        assert_eq!(hashes("git_sha=3f2a9c1b7e"), ["3f2a9c1b7e"]);
+        assert_eq!(hashes("container_id: 4a1b2c3d4e5f"), ["4a1b2c3d4e5f"]);
+    }
+
+    #[test]
+    fn bare_full_length_hashes() {
+        let sha1 = "e83c5163316f89bfbde7d9ab23ca2e25604af290";
+        assert_eq!(hashes(&format!("merged {sha1} into main")), [sha1]);
+        assert_eq!(hashes(&format!("container {SHA256} started")), [SHA256]);
+    }
+
+    #[test]
+    fn short_bare_hex_is_not_a_hash() {
+        assert!(span_texts("built 3f2a9c1 in 2s", &make_finder()).is_empty());
+        assert!(span_texts("deadbeefcafe", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn all_digit_runs_are_left_to_numbers() {
+        assert!(span_texts("commit 1234567 files", &make_finder()).is_empty());
+        assert!(span_texts("id 1234567890123456789012345678901234567890", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn hex_embedded_in_a_longer_word_is_not_a_hash() {
+        let input = format!("x{SHA256}");
+        assert!(span_texts(&input, &make_finder()).is_empty());
+    }
+}
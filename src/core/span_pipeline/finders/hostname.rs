@@ -0,0 +1,189 @@
+use super::build_regex;
+use memchr::memchr;
+use regex::Regex;
+
+use crate::core::config::HostnameConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Top-level domains that Java and similar ecosystems put first in package
+/// names (`com.example.app`, `io.netty.channel`). Subdomain-like TLDs such as
+/// `dev`, `app` or country codes are left out: `dev.example.com` and
+/// `de.wikipedia.org` are hosts.
+const REVERSE_DNS_ROOTS: [&str; 7] = ["com", "org", "net", "edu", "gov", "mil", "io"];
+
+#[derive(Debug, Clone)]
+pub(crate) struct HostnameFinder {
+    regex: Regex,
+    suffixes: Vec<String>,
+    segment: StyleId,
+    dot: StyleId,
+}
+
+impl HostnameFinder {
+    pub fn new(config: HostnameConfig, palette: &mut Palette) -> Self {
+        // Dotted labels ending in an alphabetic label; whether that tail is a
+        // known suffix is checked afterwards, so the regex stays suffix-agnostic.
+        let pattern = r"(?x)
+            \b
+            (?:[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?\.)+
+            [A-Za-z]{2,63}
+            \b
+        ";
+        let regex = build_regex(pattern);
+
+        let suffixes = config
+            .suffixes
+            .iter()
+            .map(|s| s.trim_start_matches('.').to_ascii_lowercase())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        Self {
+            regex,
+            suffixes,
+            segment: palette.intern(config.segment),
+            dot: palette.intern(config.dot),
+        }
+    }
+
+    /// Whether `host` ends in an allowed suffix with at least one label in
+    /// front of it. The suffix must be lowercase, as hostnames are in logs;
+    /// `com.example.App` is a class name.
+    fn has_allowed_suffix(&self, host: &str) -> bool {
+        self.suffixes.iter().any(|suffix| {
+            host.len() > suffix.len() + 1
+                && host.as_bytes()[host.len() - suffix.len() - 1] == b'.'
+                && host.ends_with(suffix.as_str())
+        })
+    }
+}
+
+/// Whether `host` reads as a reverse-DNS package name: `com.example.app`.
+fn is_package_name(host: &str) -> bool {
+    host.split('.')
+        .next()
+        .is_some_and(|first| REVERSE_DNS_ROOTS.contains(&first))
+}
+
+/// A hostname must stand alone: URLs, emails and paths already own the hosts
+/// and file names embedded in them.
+fn is_standalone(bytes: &[u8], start: usize, end: usize) -> bool {
+    let joins = |b: u8| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'@');
+
+    let before_ok = start == 0 || !(joins(bytes[start - 1]) || matches!(bytes[start - 1], b'.' | b'/'));
+    let after_ok = match bytes.get(end) {
+        None => true,
+        Some(&b'.') => !bytes.get(end + 1).is_some_and(u8::is_ascii_alphanumeric),
+        Some(&b) => !joins(b),
+    };
+
+    before_ok && after_ok
+}
+
+impl Finder for HostnameFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if memchr(b'.', input.as_bytes()).is_none() {
+            return;
+        }
+
+        let bytes = input.as_bytes();
+        for m in self.regex.find_iter(input) {
+            if !is_standalone(bytes, m.start(), m.end())
+                || !self.has_allowed_suffix(m.as_str())
+                || is_package_name(m.as_str())
+            {
+                continue;
+            }
+
+            let mut pos = m.start();
+            for label in m.as_str().split('.') {
+                if pos > m.start() {
+                    collector.push(pos - 1, pos, self.dot);
+                }
+                collector.push(pos, pos + label.len(), self.segment);
+                pos += label.len() + 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> HostnameFinder {
+        HostnameFinder::new(HostnameConfig::default(), &mut Palette::new())
+    }
+
+    #[test]
+    fn internal_fqdn_splits_segments_and_dots() {
+        let texts = span_texts("connecting to db-3.prod.eu-west-1.internal", &make_finder());
+        assert_eq!(texts, ["db-3", ".", "prod", ".", "eu-west-1", ".", "internal"]);
+    }
+
+    #[test]
+    fn labels_keep_their_case_but_the_suffix_is_lowercase() {
+        let texts = span_texts("resolved API.Example.com to 10.0.0.1", &make_finder());
+        assert_eq!(texts, ["API", ".", "Example", ".", "com"]);
+        assert!(span_texts("resolved API.Example.COM", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn trailing_sentence_period_is_allowed() {
+        let texts = span_texts("pulled from ghcr.io.", &make_finder());
+        assert_eq!(texts, ["ghcr", ".", "io"]);
+    }
+
+    #[test]
+    fn file_names_and_package_names_do_not_match() {
+        assert!(span_texts("loaded config.yaml and app.log", &make_finder()).is_empty());
+        assert!(span_texts("at com.example.service.Handler", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn reverse_dns_package_names_do_not_match() {
+        let finder = make_finder();
+        for input in [
+            "package com.example.app;",
+            "com.example.App started",
+            "io.netty.channel.Dev",
+        ] {
+            assert!(span_texts(input, &finder).is_empty(), "{input}");
+        }
+        assert_eq!(span_texts("dev.example.com", &finder).concat(), "dev.example.com");
+    }
+
+    #[test]
+    fn a_bare_suffix_is_not_a_hostname() {
+        assert!(span_texts("the .internal zone", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn hosts_inside_urls_emails_and_paths_are_left_alone() {
+        assert!(span_texts("https://example.com/a", &make_finder()).is_empty());
+        assert!(span_texts("ops@example.com", &make_finder()).is_empty());
+        assert!(span_texts("/srv/www/example.com/index", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn custom_suffixes_replace_the_defaults() {
+        let finder = HostnameFinder::new(
+            HostnameConfig {
+                segment: Style::new().fg(Color::Blue),
+                dot: Style::new().fg(Color::Red),
+                suffixes: vec![".corp.acme".to_string()],
+            },
+            &mut Palette::new(),
+        );
+
+        assert_eq!(
+            span_texts("ldap-1.corp.acme up", &finder),
+            ["ldap-1", ".", "corp", ".", "acme"]
+        );
+        assert!(span_texts("example.com up", &finder).is_empty());
+    }
+}
@@ -12,6 +12,8 @@ pub(crate) struct IpV4Finder {
     regex: Regex,
     number: StyleId,
     separator: StyleId,
+    prefix_length: StyleId,
+    port: StyleId,
 }
 
 impl IpV4Finder {
@@ -21,7 +23,7 @@ impl IpV4Finder {
             (?P<o2>\d{1,3})(?P<d2>\.)
             (?P<o3>\d{1,3})(?P<d3>\.)
             (?P<o4>\d{1,3})
-            (?:(?P<slash>/)(?P<mask>\d{1,2}))?
+            (?:(?P<slash>/)(?P<mask>\d{1,2}) | (?P<colon>:)(?P<port>\d{1,5}))?
             \b";
         let regex = build_regex(pattern);
 
@@ -29,6 +31,8 @@ impl IpV4Finder {
             regex,
             number: palette.intern(config.number),
             separator: palette.intern(config.separator),
+            prefix_length: palette.intern(config.prefix_length),
+            port: palette.intern(config.port),
         }
     }
 }
@@ -61,7 +65,14 @@ impl Finder for IpV4Finder {
                 if let Some(slash) = caps.name("slash") {
                     collector.push(slash.start(), slash.end(), self.separator);
                     let mask = caps.name("mask").unwrap();
-                    collector.push(mask.start(), mask.end(), self.number);
+                    collector.push(mask.start(), mask.end(), self.prefix_length);
+                }
+                // An out-of-range port is left off; the address still counts.
+                if let (Some(colon), Some(port)) = (caps.name("colon"), caps.name("port"))
+                    && port.as_str().parse::<u16>().is_ok()
+                {
+                    collector.push(colon.start(), colon.end(), self.separator);
+                    collector.push(port.start(), port.end(), self.port);
                 }
             }
         }
@@ -79,6 +90,8 @@ mod tests {
             IpV4Config {
                 number: Style::new().fg(Color::Blue),
                 separator: Style::new().fg(Color::Red),
+                prefix_length: Style::new().fg(Color::Green),
+                port: Style::new().fg(Color::Cyan),
             },
             &mut Palette::new(),
         )
@@ -99,6 +112,35 @@ mod tests {
         assert!(texts.contains(&"24"));
     }
 
+    #[test]
+    fn prefix_length_is_part_of_the_address() {
+        let finder = make_finder();
+        let mut collector = Collector::new();
+        finder.find_spans("route 10.0.0.0/24 added", &mut collector);
+        let spans = collector.into_spans();
+        let last = spans.last().unwrap();
+        assert_eq!(&"route 10.0.0.0/24 added"[last.start..last.end], "24");
+        assert_eq!(last.style, finder.prefix_length);
+    }
+
+    #[test]
+    fn ipv4_with_port() {
+        let finder = make_finder();
+        let input = "connect to 10.0.0.1:8080 failed";
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+        let spans = collector.into_spans();
+        let texts: Vec<&str> = spans.iter().map(|s| &input[s.start..s.end]).collect();
+        assert_eq!(texts, ["10", ".", "0", ".", "0", ".", "1", ":", "8080"]);
+        assert_eq!(spans.last().unwrap().style, finder.port);
+    }
+
+    #[test]
+    fn port_over_65535_is_left_off() {
+        let texts = span_texts("10.0.0.1:70000", &make_finder());
+        assert_eq!(texts, ["10", ".", "0", ".", "0", ".", "1"]);
+    }
+
     #[test]
     fn all_zeros() {
         let texts = span_texts("0.0.0.0", &make_finder());
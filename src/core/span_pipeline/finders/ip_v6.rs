@@ -14,11 +14,18 @@ pub(crate) struct IpV6Finder {
     number: StyleId,
     letter: StyleId,
     separator: StyleId,
+    prefix_length: StyleId,
+    port: StyleId,
 }
 
 impl IpV6Finder {
     pub fn new(config: IpV6Config, palette: &mut Palette) -> Self {
-        let pattern = r"([0-9a-fA-F:.]{3,})(?:(/)(\d{1,3}))?";
+        // The bracketed form is the only one that can carry a port: a bare
+        // `::1:443` is itself a valid address.
+        let pattern = r"(?x)
+            (?P<open>\[)(?P<bracketed>[0-9a-fA-F:.]{2,})(?P<close>\])(?:(?P<colon>:)(?P<port>\d{1,5}))?
+          | (?P<addr>[0-9a-fA-F:.]{3,})(?:(?P<slash>/)(?P<mask>\d{1,3}))?
+        ";
         let regex = build_regex(pattern);
 
         Self {
@@ -26,6 +33,8 @@ impl IpV6Finder {
             number: palette.intern(config.number),
             letter: palette.intern(config.letter),
             separator: palette.intern(config.separator),
+            prefix_length: palette.intern(config.prefix_length),
+            port: palette.intern(config.port),
         }
     }
 }
@@ -37,13 +46,13 @@ impl Finder for IpV6Finder {
         }
 
         for caps in self.regex.captures_iter(input) {
-            let valid_addr = caps[1].parse::<Ipv6Addr>().is_ok();
+            let addr_match = caps.name("addr").or_else(|| caps.name("bracketed")).unwrap();
+            let valid_addr = addr_match.as_str().parse::<Ipv6Addr>().is_ok();
             let valid_mask = caps
-                .get(3)
+                .name("mask")
                 .is_none_or(|m| m.as_str().parse::<u8>().is_ok_and(|v| v <= 128));
 
             if valid_addr && valid_mask {
-                let addr_match = caps.get(1).unwrap();
                 let addr = addr_match.as_str();
                 let offset = addr_match.start();
 
@@ -57,9 +66,20 @@ impl Finder for IpV6Finder {
                     collector.push(offset + i, offset + i + c.len_utf8(), style);
                 }
 
-                if let (Some(slash), Some(netmask)) = (caps.get(2), caps.get(3)) {
+                if let (Some(slash), Some(netmask)) = (caps.name("slash"), caps.name("mask")) {
                     collector.push(slash.start(), slash.end(), self.separator);
-                    collector.push(netmask.start(), netmask.end(), self.number);
+                    collector.push(netmask.start(), netmask.end(), self.prefix_length);
+                }
+                if let (Some(open), Some(close)) = (caps.name("open"), caps.name("close")) {
+                    collector.push(open.start(), open.end(), self.separator);
+                    collector.push(close.start(), close.end(), self.separator);
+                }
+                // An out-of-range port is left off; the address still counts.
+                if let (Some(colon), Some(port)) = (caps.name("colon"), caps.name("port"))
+                    && port.as_str().parse::<u16>().is_ok()
+                {
+                    collector.push(colon.start(), colon.end(), self.separator);
+                    collector.push(port.start(), port.end(), self.port);
                 }
             }
         }
@@ -77,6 +97,8 @@ mod tests {
                 number: Style::new().fg(Color::Blue),
                 letter: Style::new().fg(Color::Yellow),
                 separator: Style::new().fg(Color::Red),
+                prefix_length: Style::new().fg(Color::Green),
+                port: Style::new().fg(Color::Cyan),
             },
             &mut Palette::new(),
         )
@@ -127,6 +149,32 @@ mod tests {
         assert_eq!(end, input.len());
     }
 
+    #[test]
+    fn bracketed_with_port() {
+        let input = "listening on [::1]:443";
+        let (start, end) = matched_range(input).unwrap();
+        assert_eq!(&input[start..end], "[::1]:443");
+
+        let finder = make_finder();
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+        let port = collector.into_spans().pop().unwrap();
+        assert_eq!(&input[port.start..port.end], "443");
+        assert_eq!(port.style, finder.port);
+    }
+
+    #[test]
+    fn bracketed_without_port() {
+        let input = "peer [2001:db8::1] closed";
+        let (start, end) = matched_range(input).unwrap();
+        assert_eq!(&input[start..end], "[2001:db8::1]");
+    }
+
+    #[test]
+    fn bracketed_non_address_no_match() {
+        assert_eq!(span_count("[12:30]:443"), 0);
+    }
+
     #[test]
     fn mask_over_128_no_match() {
         assert_eq!(span_count("fe80::/129"), 0);
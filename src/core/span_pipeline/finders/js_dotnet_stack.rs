@@ -0,0 +1,285 @@
+use super::build_regex;
+use regex::{Captures, Regex};
+
+use crate::core::config::JsDotnetStackTraceConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+#[derive(Debug, Clone)]
+pub(crate) struct JsDotnetStackFinder {
+    marker_regex: Regex,
+    header_regex: Regex,
+    js_frame_regex: Regex,
+    js_bare_frame_regex: Regex,
+    dotnet_frame_regex: Regex,
+    caused_by: StyleId,
+    package: StyleId,
+    exception: StyleId,
+    frame: StyleId,
+    file: StyleId,
+    unknown_source: StyleId,
+    line_number: StyleId,
+}
+
+impl JsDotnetStackFinder {
+    pub fn new(config: JsDotnetStackTraceConfig, palette: &mut Palette) -> Self {
+        // .NET separators between an exception and its inner exception.
+        let marker_pattern = r"(?m)^\s*(?P<marker>---\x20End\x20of\x20(?u:[^\n])*?---)[\x20\t]*$";
+        let marker_regex = build_regex(marker_pattern);
+
+        // `TypeError: …`, `Error [ERR_X]: …`, `Unhandled exception. System.X: …`,
+        // and the nested forms `---> System.X: …` and `[cause]: Error: …`.
+        let header_pattern = r"(?xm)
+            ^\s*
+            (?P<marker>--->\x20|\[cause\]:\x20)?
+            (?:Unhandled\x20exception\.\x20|Uncaught\x20)?
+            (?P<package>(?:[A-Za-z_][A-Za-z0-9_]*\.)*)
+            (?P<class>[A-Z][A-Za-z0-9_$]*(?:Error|Exception)|Error)
+            (?P<code>\x20\[[A-Z0-9_]+\])?
+            (?P<colon>:)
+        ";
+        let header_regex = build_regex(header_pattern);
+
+        // V8 puts a space before the location's parenthesis, which keeps
+        // these apart from JVM frames.
+        let js_frame_pattern = r"(?xm)
+            ^\s+
+            (?P<head>at\x20(?:(?:async|new)\x20)?(?u:[^\s(][^(\n]*?)\x20\()
+            (?:
+                (?P<unknown>native|<anonymous>|index\x20\d+|unknown\x20location)
+              | (?P<file>(?u:[^\s()])+?)
+                (?P<line_colon>:)(?P<line>\d+)
+                (?:(?P<col_colon>:)(?P<col>\d+))?
+            )
+            (?P<close>\))
+            [\x20\t]*$
+        ";
+        let js_frame_regex = build_regex(js_frame_pattern);
+
+        // Anonymous V8 frames: `at /app/src/x.js:10:15`.
+        let js_bare_frame_pattern = r"(?xm)
+            ^\s+
+            (?P<head>at\x20(?:async\x20)?)
+            (?P<file>(?u:[^\s()])+?)
+            (?P<line_colon>:)(?P<line>\d+)
+            (?P<col_colon>:)(?P<col>\d+)
+            [\x20\t]*$
+        ";
+        let js_bare_frame_regex = build_regex(js_bare_frame_pattern);
+
+        // `at Ns.Class.Method(String arg) in C:\src\File.cs:line 42`
+        let dotnet_frame_pattern = r"(?xm)
+            ^\s+
+            (?P<head>at\x20(?u:[^\s(])+\((?P<args>(?u:[^)\n])*)\))
+            (?:
+                (?P<in>\x20in\x20)
+                (?P<file>(?u:[^\n])+?)
+                (?P<line_colon>:line\x20)(?P<line>\d+)
+            )?
+            [\x20\t]*$
+        ";
+        let dotnet_frame_regex = build_regex(dotnet_frame_pattern);
+
+        Self {
+            marker_regex,
+            header_regex,
+            js_frame_regex,
+            js_bare_frame_regex,
+            dotnet_frame_regex,
+            caused_by: palette.intern(config.caused_by),
+            package: palette.intern(config.package),
+            exception: palette.intern(config.exception),
+            frame: palette.intern(config.frame),
+            file: palette.intern(config.file),
+            unknown_source: palette.intern(config.unknown_source),
+            line_number: palette.intern(config.line_number),
+        }
+    }
+
+    /// Pushes the optional `file`, `:line` and `:col` groups of a frame.
+    fn push_location(&self, caps: &Captures, collector: &mut Collector) {
+        for (name, style) in [
+            ("file", self.file),
+            ("line_colon", self.frame),
+            ("line", self.line_number),
+            ("col_colon", self.frame),
+            ("col", self.line_number),
+        ] {
+            if let Some(m) = caps.name(name) {
+                collector.push(m.start(), m.end(), style);
+            }
+        }
+    }
+}
+
+/// JVM frames share the .NET shape `at a.b.c(…)`; theirs hold a `File.java:12`
+/// location or a JVM placeholder instead of a parameter list.
+fn is_jvm_location(args: &str) -> bool {
+    args.contains(':') || matches!(args, "Unknown Source" | "Native Method" | "<generated>")
+}
+
+impl Finder for JsDotnetStackFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if input.contains("--- End of ") {
+            for caps in self.marker_regex.captures_iter(input) {
+                let marker = caps.name("marker").unwrap();
+                collector.push(marker.start(), marker.end(), self.caused_by);
+            }
+        }
+
+        if input.contains("Error") || input.contains("Exception") {
+            for caps in self.header_regex.captures_iter(input) {
+                if let Some(marker) = caps.name("marker") {
+                    collector.push(marker.start(), marker.end() - 1, self.caused_by);
+                }
+                let package = caps.name("package").unwrap();
+                if !package.is_empty() {
+                    collector.push(package.start(), package.end(), self.package);
+                }
+                let class = caps.name("class").unwrap();
+                collector.push(class.start(), class.end(), self.exception);
+                if let Some(code) = caps.name("code") {
+                    collector.push(code.start() + 1, code.end(), self.unknown_source);
+                }
+                let colon = caps.name("colon").unwrap();
+                collector.push(colon.start(), colon.end(), self.frame);
+            }
+        }
+
+        if !input.contains("at ") {
+            return;
+        }
+
+        for caps in self.js_frame_regex.captures_iter(input) {
+            let head = caps.name("head").unwrap();
+            let close = caps.name("close").unwrap();
+            collector.push(head.start(), head.end(), self.frame);
+            if let Some(unknown) = caps.name("unknown") {
+                collector.push(unknown.start(), unknown.end(), self.unknown_source);
+            }
+            self.push_location(&caps, collector);
+            collector.push(close.start(), close.end(), self.frame);
+        }
+
+        for caps in self.js_bare_frame_regex.captures_iter(input) {
+            let head = caps.name("head").unwrap();
+            collector.push(head.start(), head.end(), self.frame);
+            self.push_location(&caps, collector);
+        }
+
+        for caps in self.dotnet_frame_regex.captures_iter(input) {
+            let in_kw = caps.name("in");
+            if in_kw.is_none() && is_jvm_location(caps.name("args").unwrap().as_str()) {
+                continue;
+            }
+
+            let head = caps.name("head").unwrap();
+            collector.push(head.start(), head.end(), self.frame);
+            if let Some(in_kw) = in_kw {
+                collector.push(in_kw.start(), in_kw.end(), self.frame);
+            }
+            self.push_location(&caps, collector);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> JsDotnetStackFinder {
+        JsDotnetStackFinder::new(
+            JsDotnetStackTraceConfig {
+                caused_by: Style::new().bold(),
+                package: Style::new().fg(Color::Red).faint(),
+                exception: Style::new().fg(Color::Red),
+                frame: Style::new().fg(Color::Red).faint(),
+                file: Style::new().fg(Color::Yellow),
+                unknown_source: Style::new().fg(Color::Yellow).faint(),
+                line_number: Style::new().fg(Color::Cyan),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn js_header_with_error_code() {
+        let texts = span_texts("Error [ERR_INVALID_ARG_TYPE]: bad argument", &make_finder());
+        assert_eq!(texts, ["Error", "[ERR_INVALID_ARG_TYPE]", ":"]);
+    }
+
+    #[test]
+    fn js_frame_with_function_and_location() {
+        let texts = span_texts("    at handler (/app/src/x.js:10:15)", &make_finder());
+        assert_eq!(texts, ["at handler (", "/app/src/x.js", ":", "10", ":", "15", ")"]);
+    }
+
+    #[test]
+    fn js_frame_with_node_internal_module() {
+        let texts = span_texts(
+            "    at process.processTicksAndRejections (node:internal/process/task_queues:95:5)",
+            &make_finder(),
+        );
+        assert!(texts.contains(&"node:internal/process/task_queues"));
+        assert!(texts.contains(&"95"));
+    }
+
+    #[test]
+    fn js_placeholders_are_unknown_sources() {
+        let finder = make_finder();
+        for (input, placeholder) in [
+            ("    at async Promise.all (index 0)", "index 0"),
+            ("    at Array.forEach (<anonymous>)", "<anonymous>"),
+        ] {
+            let mut collector = Collector::new();
+            finder.find_spans(input, &mut collector);
+            let spans = collector.into_spans();
+            let span = spans.iter().find(|s| &input[s.start..s.end] == placeholder).unwrap();
+            assert_eq!(span.style, finder.unknown_source);
+        }
+    }
+
+    #[test]
+    fn js_anonymous_frame_without_function() {
+        let texts = span_texts("    at /app/src/x.js:10:15", &make_finder());
+        assert_eq!(texts, ["at ", "/app/src/x.js", ":", "10", ":", "15"]);
+    }
+
+    #[test]
+    fn dotnet_frame_with_windows_source_location() {
+        let texts = span_texts(
+            r"   at Acme.Billing.Invoice.Render(String template) in C:\src\Invoice.cs:line 42",
+            &make_finder(),
+        );
+        assert_eq!(
+            texts,
+            [
+                "at Acme.Billing.Invoice.Render(String template) in ",
+                r"C:\src\Invoice.cs",
+                ":line ",
+                "42"
+            ]
+        );
+    }
+
+    #[test]
+    fn dotnet_inner_exception_markers() {
+        let texts = span_texts(" ---> System.IO.IOException: pipe closed", &make_finder());
+        assert_eq!(texts, ["--->", "System.IO.", "IOException", ":"]);
+        let marker = "   --- End of inner exception stack trace ---";
+        assert_eq!(
+            span_texts(marker, &make_finder()),
+            ["--- End of inner exception stack trace ---"]
+        );
+    }
+
+    #[test]
+    fn jvm_frames_are_left_to_the_jvm_finder() {
+        let input = "        at com.example.EmailService.send(EmailService.kt:171)";
+        assert!(span_texts(input, &make_finder()).is_empty());
+        assert!(span_texts("        at sun.misc.Unsafe.park(Native Method)", &make_finder()).is_empty());
+    }
+}
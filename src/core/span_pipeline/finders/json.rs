@@ -1,10 +1,15 @@
-use serde::Deserialize;
+use memchr::memchr2_iter;
+use serde::de::IgnoredAny;
 
 use crate::core::config::JsonConfig;
 
 use super::super::palette::{Palette, StyleId};
 use super::super::span::{Collector, Finder};
 
+/// Failed parses allowed per line. Each one can scan to the end of the line,
+/// so this keeps lines full of stray braces linear.
+const MAX_FAILED_ATTEMPTS: usize = 8;
+
 #[derive(Debug, Clone)]
 pub(crate) struct JsonFinder {
     key: StyleId,
@@ -13,6 +18,11 @@ pub(crate) struct JsonFinder {
     square_bracket: StyleId,
     comma: StyleId,
     colon: StyleId,
+    string: StyleId,
+    number: StyleId,
+    boolean: StyleId,
+    null: StyleId,
+    brackets: Vec<StyleId>,
 }
 
 impl JsonFinder {
@@ -24,88 +34,80 @@ impl JsonFinder {
             square_bracket: palette.intern(config.square_bracket),
             comma: palette.intern(config.comma),
             colon: palette.intern(config.colon),
+            string: palette.intern(config.string),
+            number: palette.intern(config.number),
+            boolean: palette.intern(config.boolean),
+            null: palette.intern(config.null),
+            brackets: config.brackets.into_iter().map(|style| palette.intern(style)).collect(),
         }
     }
-}
-
-impl Finder for JsonFinder {
-    fn find_spans(&self, input: &str, collector: &mut Collector) {
-        let first = input.as_bytes().iter().find(|b| !b.is_ascii_whitespace());
-        if first != Some(&b'{') && first != Some(&b'[') {
-            return;
-        }
 
-        // Validate it's JSON without allocating the tree, then style the
-        // structural tokens at their original byte positions.
-        let mut de = serde_json::Deserializer::from_str(input);
-        if serde::de::IgnoredAny::deserialize(&mut de).is_err() || de.end().is_err() {
-            return;
+    /// The bracket style at nesting `depth`: cycled through `brackets` when
+    /// set, otherwise the curly or square bracket style.
+    fn bracket(&self, depth: usize, curly: bool) -> StyleId {
+        match self.brackets.len() {
+            0 if curly => self.curly_bracket,
+            0 => self.square_bracket,
+            n => self.brackets[depth % n],
         }
+    }
 
-        let bytes = input.as_bytes();
-        let mut in_string = false;
-        let mut escape_next = false;
+    /// Walks the token stream of the validated JSON in `bytes` and styles each
+    /// token; `offset` is where `bytes` starts in the line. Validity means
+    /// every token is well-formed, so the walk never has to recover from
+    /// errors.
+    fn push_tokens(&self, bytes: &[u8], offset: usize, collector: &mut Collector) {
+        // Open containers, innermost last; `true` for objects.
+        let mut open: Vec<bool> = Vec::new();
+        // A string directly after `{` or an object's `,` is a key.
+        let mut expect_key = false;
 
         let mut i = 0;
         while i < bytes.len() {
-            let b = bytes[i];
-
-            if escape_next {
-                escape_next = false;
-                i += 1;
-                continue;
-            }
-
-            if in_string {
-                if b == b'\\' {
-                    escape_next = true;
-                } else if b == b'"' {
-                    collector.push(i, i + 1, self.quote_token);
-                    in_string = false;
+            match bytes[i] {
+                b @ (b'{' | b'[') => {
+                    let curly = b == b'{';
+                    collector.push(offset + i, offset + i + 1, self.bracket(open.len(), curly));
+                    open.push(curly);
+                    expect_key = curly;
+                }
+                b'}' | b']' => {
+                    let curly = open.pop().unwrap_or_default();
+                    collector.push(offset + i, offset + i + 1, self.bracket(open.len(), curly));
+                    expect_key = false;
+                }
+                b',' => {
+                    collector.push(offset + i, offset + i + 1, self.comma);
+                    expect_key = open.last() == Some(&true);
+                }
+                b':' => {
+                    collector.push(offset + i, offset + i + 1, self.colon);
+                    expect_key = false;
                 }
-                i += 1;
-                continue;
-            }
-
-            match b {
-                b'{' | b'}' => collector.push(i, i + 1, self.curly_bracket),
-                b'[' | b']' => collector.push(i, i + 1, self.square_bracket),
-                b',' => collector.push(i, i + 1, self.comma),
-                b':' => collector.push(i, i + 1, self.colon),
                 b'"' => {
-                    collector.push(i, i + 1, self.quote_token);
-                    in_string = true;
-
-                    // A quoted string is a key unless preceded by `:`.
-                    // Only whitespace can appear between structural tokens in
-                    // validated JSON, so a short backward scan is sufficient.
-                    let preceded_by_colon = bytes[..i]
+                    let end = string_end(bytes, i);
+                    let content = if expect_key { self.key } else { self.string };
+                    collector.push(offset + i, offset + i + 1, self.quote_token);
+                    collector.push(offset + i + 1, offset + end, content);
+                    collector.push(offset + end, offset + end + 1, self.quote_token);
+                    i = end;
+                }
+                b't' | b'f' => {
+                    let len = if bytes[i] == b't' { 4 } else { 5 };
+                    collector.push(offset + i, offset + i + len, self.boolean);
+                    i += len - 1;
+                }
+                b'n' => {
+                    collector.push(offset + i, offset + i + 4, self.null);
+                    i += 3;
+                }
+                b'-' | b'0'..=b'9' => {
+                    let len = bytes[i..]
                         .iter()
-                        .rev()
-                        .find(|b| !b.is_ascii_whitespace())
-                        .is_some_and(|&b| b == b':');
-
-                    if !preceded_by_colon {
-                        // This is a key — style the content
-                        let start = i + 1;
-                        let mut j = start;
-                        while j < bytes.len() {
-                            if bytes[j] == b'\\' {
-                                j += 2;
-                                continue;
-                            }
-                            if bytes[j] == b'"' {
-                                if start < j {
-                                    collector.push(start, j, self.key);
-                                }
-                                collector.push(j, j + 1, self.quote_token);
-                                in_string = false;
-                                i = j;
-                                break;
-                            }
-                            j += 1;
-                        }
-                    }
+                        .take_while(|b| matches!(b, b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E'))
+                        .count();
+                    collector.push(offset + i, offset + i + len, self.number);
+                    i += len - 1;
                 }
                 _ => {}
             }
@@ -115,6 +117,65 @@ impl Finder for JsonFinder {
     }
 }
 
+/// The index of the quote closing the string opened at `open`.
+fn string_end(bytes: &[u8], open: usize) -> usize {
+    let mut j = open + 1;
+    while bytes[j] != b'"' {
+        j += if bytes[j] == b'\\' { 2 } else { 1 };
+    }
+    j
+}
+
+/// The end of the JSON object or array starting at `start`, if one does.
+fn json_end(input: &str, start: usize) -> Option<usize> {
+    // Validates without allocating the tree.
+    let mut values = serde_json::Deserializer::from_str(&input[start..]).into_iter::<IgnoredAny>();
+    match values.next() {
+        Some(Ok(_)) => Some(start + values.byte_offset()),
+        _ => None,
+    }
+}
+
+/// Whether the bracket at `start` may open embedded JSON. `{` must hold a key
+/// and `[` a container or string, so `[42]`, `pid[4242]` and `{}` stay with
+/// the other finders.
+fn is_candidate(bytes: &[u8], start: usize) -> bool {
+    let next = bytes[start + 1..].iter().find(|b| !b.is_ascii_whitespace());
+    match bytes[start] {
+        b'{' => next == Some(&b'"'),
+        _ => matches!(next, Some(b'{' | b'[' | b'"')),
+    }
+}
+
+impl Finder for JsonFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+        let line_start = bytes.iter().position(|b| !b.is_ascii_whitespace());
+
+        let mut resume = 0;
+        let mut failed = 0;
+        for start in memchr2_iter(b'{', b'[', bytes) {
+            if start < resume {
+                continue;
+            }
+            // A bracket opening the line may start any object or array.
+            if Some(start) != line_start && !is_candidate(bytes, start) {
+                continue;
+            }
+
+            if let Some(end) = json_end(input, start) {
+                self.push_tokens(&bytes[start..end], start, collector);
+                resume = end;
+            } else {
+                failed += 1;
+                if failed == MAX_FAILED_ATTEMPTS {
+                    return;
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::span_texts;
@@ -130,11 +191,26 @@ mod tests {
                 square_bracket: Style::new().fg(Color::Green),
                 comma: Style::new().fg(Color::Red),
                 colon: Style::new().fg(Color::Magenta),
+                string: Style::new().fg(Color::BrightYellow),
+                number: Style::new().fg(Color::BrightCyan),
+                boolean: Style::new().fg(Color::BrightGreen),
+                null: Style::new().fg(Color::BrightRed),
+                brackets: Vec::new(),
             },
             &mut Palette::new(),
         )
     }
 
+    fn styled(finder: &JsonFinder, input: &str) -> Vec<(String, StyleId)> {
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+        collector
+            .into_spans()
+            .iter()
+            .map(|s| (input[s.start..s.end].to_string(), s.style))
+            .collect()
+    }
+
     #[test]
     fn simple_json_object() {
         let input = r#"{"name": "John", "age": 30}"#;
@@ -222,15 +298,114 @@ mod tests {
     }
 
     #[test]
-    fn value_content_not_styled() {
-        // The backward scan finds `:` before value strings — their content
-        // should NOT appear in spans, only their quote tokens.
-        let input = r#"{"a": "x", "b": {"c": "y"}}"#;
-        let texts = span_texts(input, &make_finder());
-        assert!(texts.contains(&"a"));
-        assert!(texts.contains(&"b"));
-        assert!(texts.contains(&"c"));
-        assert!(!texts.contains(&"x"), "value content should not be styled");
-        assert!(!texts.contains(&"y"), "nested value content should not be styled");
+    fn embedded_object_after_log_prefix() {
+        let finder = make_finder();
+        let input = r#"2026-10-17T12:00:00Z INFO request {"user":42,"ok":true}"#;
+        let spans = styled(&finder, input);
+        assert_eq!(spans.first(), Some(&("{".into(), finder.curly_bracket)));
+        assert!(spans.contains(&("user".into(), finder.key)));
+        assert!(spans.contains(&("42".into(), finder.number)));
+        assert!(spans.contains(&("true".into(), finder.boolean)));
+    }
+
+    #[test]
+    fn several_embedded_values_per_line() {
+        let finder = make_finder();
+        let spans = styled(&finder, r#"req {"a":1} resp {"b":2} tags ["x"] done"#);
+        for key in ["a", "b"] {
+            assert!(spans.contains(&(key.into(), finder.key)));
+        }
+        assert!(spans.contains(&("x".into(), finder.string)));
+    }
+
+    #[test]
+    fn invalid_prefix_does_not_hide_later_json() {
+        let finder = make_finder();
+        let spans = styled(&finder, r#"{"oops" then {"a":1}"#);
+        assert!(spans.contains(&("a".into(), finder.key)));
+        assert!(!spans.iter().any(|(text, _)| text == "oops"));
+    }
+
+    #[test]
+    fn bracketed_log_tokens_are_not_embedded_json() {
+        for input in ["sshd[4242]: accepted", "INFO [42] {} done", "took [1, 2] ms"] {
+            assert!(span_texts(input, &make_finder()).is_empty(), "{input}");
+        }
+    }
+
+    #[test]
+    fn stray_braces_stop_after_bounded_attempts() {
+        let mut input = r#"{"a" "#.repeat(MAX_FAILED_ATTEMPTS);
+        input.push_str(r#"{"late":1}"#);
+        assert!(span_texts(&input, &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn value_content_uses_the_string_style() {
+        let finder = make_finder();
+        let spans = styled(&finder, r#"{"a": "x", "b": {"c": "y"}}"#);
+        assert!(spans.contains(&("a".into(), finder.key)));
+        assert!(spans.contains(&("c".into(), finder.key)));
+        assert!(spans.contains(&("x".into(), finder.string)));
+        assert!(spans.contains(&("y".into(), finder.string)));
+    }
+
+    #[test]
+    fn value_types_get_their_own_styles() {
+        let finder = make_finder();
+        let spans = styled(&finder, r#"{"n": -1.5e3, "t": true, "f": false, "z": null}"#);
+        assert!(spans.contains(&("-1.5e3".into(), finder.number)));
+        assert!(spans.contains(&("true".into(), finder.boolean)));
+        assert!(spans.contains(&("false".into(), finder.boolean)));
+        assert!(spans.contains(&("null".into(), finder.null)));
+    }
+
+    #[test]
+    fn strings_in_arrays_are_values() {
+        let finder = make_finder();
+        let spans = styled(&finder, r#"{"tags": ["a", "b"], "k": 1}"#);
+        assert!(spans.contains(&("a".into(), finder.string)));
+        assert!(spans.contains(&("b".into(), finder.string)));
+        assert!(spans.contains(&("k".into(), finder.key)));
+    }
+
+    #[test]
+    fn escaped_quotes_and_colons_inside_strings() {
+        let finder = make_finder();
+        let spans = styled(&finder, r#"{"msg": "say \"a: b\"", "next": 1}"#);
+        assert!(spans.contains(&(r#"say \"a: b\""#.into(), finder.string)));
+        assert!(spans.contains(&("next".into(), finder.key)));
+    }
+
+    #[test]
+    fn brackets_cycle_through_styles_by_depth() {
+        let mut palette = Palette::new();
+        let depth_styles = [Style::new().fg(Color::Red), Style::new().fg(Color::Blue)];
+        let finder = JsonFinder::new(
+            JsonConfig {
+                brackets: depth_styles.to_vec(),
+                ..JsonConfig::default()
+            },
+            &mut palette,
+        );
+        let (red, blue) = (finder.brackets[0], finder.brackets[1]);
+
+        let brackets: Vec<(String, StyleId)> = styled(&finder, r#"{"a": [{"b": [1]}]}"#)
+            .into_iter()
+            .filter(|(text, _)| text.starts_with(['{', '}', '[', ']']))
+            .collect();
+        assert_eq!(
+            brackets,
+            [
+                ("{".into(), red),
+                ("[".into(), blue),
+                ("{".into(), red),
+                ("[".into(), blue),
+                ("]".into(), blue),
+                ("}".into(), red),
+                ("]".into(), blue),
+                ("}".into(), red),
+            ]
+        );
     }
 }
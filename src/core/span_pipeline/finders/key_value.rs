@@ -10,39 +10,74 @@ use super::super::span::{Collector, Finder};
 #[derive(Debug, Clone)]
 pub(crate) struct KeyValueFinder {
     regex: Regex,
+    separator_bytes: Vec<u8>,
     key: StyleId,
     separator: StyleId,
+    value: StyleId,
 }
 
 impl KeyValueFinder {
     pub fn new(config: KeyValueConfig, palette: &mut Palette) -> Self {
+        let key_chars: String = config
+            .key_chars
+            .chars()
+            .filter(char::is_ascii_graphic)
+            .map(|c| regex::escape(&c.to_string()))
+            .collect();
+
+        // Longest first, so `=>` is not cut short by `=`.
+        let mut separators: Vec<&str> = config
+            .separators
+            .iter()
+            .map(String::as_str)
+            .filter(|s| !s.is_empty())
+            .collect();
+        separators.sort_by_key(|s| std::cmp::Reverse(s.len()));
+        let separator_alternation = separators
+            .iter()
+            .map(|s| regex::escape(s))
+            .collect::<Vec<_>>()
+            .join("|");
+
+        let mut separator_bytes: Vec<u8> = separators.iter().map(|s| s.as_bytes()[0]).collect();
+        separator_bytes.sort_unstable();
+        separator_bytes.dedup();
+
         // The (?:^|\s) anchor is zero-width at start-of-string or consumes one
-        // whitespace byte. We use find_iter and skip that leading byte manually.
-        let pattern = r"(?:^|\s)\w+\b=";
-        let regex = build_regex(pattern);
+        // whitespace byte; the key group starts after it. A missing value is an
+        // empty one (`key= next=1`). No verbose mode: separators such as `: `
+        // carry significant spaces.
+        let pattern = format!(
+            r#"(?:^|\s)(?P<key>\w[\w{key_chars}]*)(?P<separator>{separator_alternation})(?P<value>"(?:(?u:[^"\\\n])|\\(?u:.))*"|(?u:[^\s"])+)?"#
+        );
+        let regex = build_regex(&pattern);
 
         Self {
             regex,
+            separator_bytes,
             key: palette.intern(config.key),
             separator: palette.intern(config.separator),
+            value: palette.intern(config.value),
         }
     }
 }
 
 impl Finder for KeyValueFinder {
     fn find_spans(&self, input: &str, collector: &mut Collector) {
-        if memchr(b'=', input.as_bytes()).is_none() {
+        let bytes = input.as_bytes();
+        if !self.separator_bytes.iter().any(|&b| memchr(b, bytes).is_some()) {
             return;
         }
 
-        for m in self.regex.find_iter(input) {
-            let bytes = m.as_str().as_bytes();
-            let skip = usize::from(bytes[0].is_ascii_whitespace());
-            let s = m.start() + skip;
+        for caps in self.regex.captures_iter(input) {
+            let key = caps.name("key").unwrap();
+            let separator = caps.name("separator").unwrap();
+            collector.push(key.start(), key.end(), self.key);
+            collector.push(separator.start(), separator.end(), self.separator);
 
-            // Match structure (after skip): key=
-            collector.push(s, m.end() - 1, self.key);
-            collector.push(m.end() - 1, m.end(), self.separator);
+            if let Some(value) = caps.name("value") {
+                collector.push_fallback(value.start(), value.end(), self.value);
+            }
         }
     }
 }
@@ -53,32 +88,86 @@ mod tests {
     use super::*;
     use crate::style::{Color, Style};
 
+    fn config(separators: &[&str]) -> KeyValueConfig {
+        KeyValueConfig {
+            key: Style::new().fg(Color::Red),
+            separator: Style::new().fg(Color::Yellow),
+            value: Style::new().fg(Color::Blue),
+            separators: separators.iter().map(ToString::to_string).collect(),
+            key_chars: ".-".into(),
+        }
+    }
+
     fn make_finder() -> KeyValueFinder {
-        KeyValueFinder::new(
-            KeyValueConfig {
-                key: Style::new().fg(Color::Red),
-                separator: Style::new().fg(Color::Yellow),
-            },
-            &mut Palette::new(),
-        )
+        KeyValueFinder::new(config(&["=", "=>"]), &mut Palette::new())
     }
 
     #[test]
     fn basic_key_value() {
         let texts = span_texts("Entry key=value", &make_finder());
-        assert_eq!(texts, ["key", "="]);
+        assert_eq!(texts, ["key", "=", "value"]);
     }
 
     #[test]
     fn multiple_key_values() {
         let texts = span_texts("host=localhost port=8080", &make_finder());
-        assert_eq!(texts, ["host", "=", "port", "="]);
+        assert_eq!(texts, ["host", "=", "localhost", "port", "=", "8080"]);
     }
 
     #[test]
     fn key_value_at_start_of_line() {
         let texts = span_texts("key=value", &make_finder());
-        assert_eq!(texts, ["key", "="]);
+        assert_eq!(texts, ["key", "=", "value"]);
+    }
+
+    #[test]
+    fn logfmt_line_with_quoted_and_empty_values() {
+        let input = r#"level=info msg="request \"done\" ok" err= http.status=200 user-id=4"#;
+        let texts = span_texts(input, &make_finder());
+        assert_eq!(
+            texts,
+            [
+                "level",
+                "=",
+                "info",
+                "msg",
+                "=",
+                r#""request \"done\" ok""#,
+                "err",
+                "=",
+                "http.status",
+                "=",
+                "200",
+                "user-id",
+                "=",
+                "4"
+            ]
+        );
+    }
+
+    #[test]
+    fn value_spans_are_fallbacks() {
+        let finder = make_finder();
+        let mut collector = Collector::new();
+        finder.find_spans("port=8080", &mut collector);
+
+        let mut spans = Vec::new();
+        collector.drain_into(&mut spans, &mut Vec::new(), 3);
+        assert_eq!(spans.iter().map(|s| s.priority).collect::<Vec<_>>(), [3, 3, u16::MAX]);
+        assert_eq!(spans[2].style, finder.value);
+    }
+
+    #[test]
+    fn configured_separators() {
+        let finder = KeyValueFinder::new(config(&["=", "=>", ": "]), &mut Palette::new());
+        assert_eq!(span_texts("user=>alice", &finder), ["user", "=>", "alice"]);
+        assert_eq!(span_texts("status: ok", &finder), ["status", ": ", "ok"]);
+        assert!(span_texts("status: ok", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn key_must_follow_whitespace() {
+        assert!(span_texts("/search?q=tail", &make_finder()).is_empty());
     }
 
     #[test]
@@ -0,0 +1,160 @@
+use super::build_regex;
+use memchr::memchr3;
+use regex::Regex;
+
+use crate::core::config::MacAddressConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+#[derive(Debug, Clone)]
+pub(crate) struct MacAddressFinder {
+    regex: Regex,
+    number: StyleId,
+    letter: StyleId,
+    separator: StyleId,
+}
+
+impl MacAddressFinder {
+    pub fn new(config: MacAddressConfig, palette: &mut Palette) -> Self {
+        // EUI-48 with an optional EUI-64 tail, in colon, dash and Cisco dotted
+        // form. The regex crate has no backreferences, so each separator gets
+        // its own branch to keep separators consistent within one address.
+        let pattern = r"(?x)
+            [0-9a-fA-F]{2}(?::[0-9a-fA-F]{2}){5}(?:(?::[0-9a-fA-F]{2}){2})?
+          | [0-9a-fA-F]{2}(?:-[0-9a-fA-F]{2}){5}(?:(?:-[0-9a-fA-F]{2}){2})?
+          | [0-9a-fA-F]{4}\.[0-9a-fA-F]{4}\.[0-9a-fA-F]{4}(?:\.[0-9a-fA-F]{4})?
+        ";
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            number: palette.intern(config.number),
+            letter: palette.intern(config.letter),
+            separator: palette.intern(config.separator),
+        }
+    }
+}
+
+/// An address must stand alone: a neighbouring word character or separator
+/// means the match is a slice of something longer — most often an IPv6
+/// address, whose groups the colon form would otherwise steal.
+fn is_standalone(bytes: &[u8], start: usize, end: usize) -> bool {
+    let joins = |b: u8| b.is_ascii_alphanumeric() || matches!(b, b':' | b'-' | b'_');
+
+    let before_ok = start == 0 || !(joins(bytes[start - 1]) || bytes[start - 1] == b'.');
+    let after_ok = match bytes.get(end) {
+        None => true,
+        Some(&b'.') => !bytes.get(end + 1).is_some_and(u8::is_ascii_alphanumeric),
+        Some(&b) => !joins(b),
+    };
+
+    before_ok && after_ok
+}
+
+impl Finder for MacAddressFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if memchr3(b':', b'-', b'.', input.as_bytes()).is_none() {
+            return;
+        }
+
+        let bytes = input.as_bytes();
+        for m in self.regex.find_iter(input) {
+            if !is_standalone(bytes, m.start(), m.end()) {
+                continue;
+            }
+
+            for (i, &b) in m.as_str().as_bytes().iter().enumerate() {
+                let style = match b {
+                    b'0'..=b'9' => self.number,
+                    b'a'..=b'f' | b'A'..=b'F' => self.letter,
+                    _ => self.separator,
+                };
+                collector.push(m.start() + i, m.start() + i + 1, style);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> MacAddressFinder {
+        MacAddressFinder::new(
+            MacAddressConfig {
+                number: Style::new().fg(Color::Blue),
+                letter: Style::new().fg(Color::Yellow),
+                separator: Style::new().fg(Color::Red),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    fn matched_range(input: &str) -> Option<&str> {
+        let mut collector = Collector::new();
+        make_finder().find_spans(input, &mut collector);
+        let spans = collector.into_spans();
+        let (first, last) = (spans.first()?, spans.last()?);
+        Some(&input[first.start..last.end])
+    }
+
+    #[test]
+    fn colon_form() {
+        assert_eq!(matched_range("link up aa:bb:cc:dd:ee:ff"), Some("aa:bb:cc:dd:ee:ff"));
+    }
+
+    #[test]
+    fn dash_form() {
+        assert_eq!(matched_range("hw 00-1A-2b-3C-4d-5E seen"), Some("00-1A-2b-3C-4d-5E"));
+    }
+
+    #[test]
+    fn cisco_dotted_form() {
+        assert_eq!(matched_range("Gi0/1 aabb.ccdd.ee01 learned"), Some("aabb.ccdd.ee01"));
+    }
+
+    #[test]
+    fn eui64_forms() {
+        assert_eq!(
+            matched_range("eui 02:00:5e:10:00:00:00:01"),
+            Some("02:00:5e:10:00:00:00:01")
+        );
+        assert_eq!(matched_range("eui 0200.5e10.0000.0001"), Some("0200.5e10.0000.0001"));
+    }
+
+    #[test]
+    fn trailing_sentence_period_is_allowed() {
+        assert_eq!(matched_range("from aa:bb:cc:dd:ee:ff."), Some("aa:bb:cc:dd:ee:ff"));
+    }
+
+    #[test]
+    fn digits_letters_and_separators_get_their_own_styles() {
+        let finder = make_finder();
+        let mut collector = Collector::new();
+        finder.find_spans("0a:1b:2c:3d:4e:5f", &mut collector);
+        let spans = collector.into_spans();
+
+        assert_eq!(spans.len(), 17);
+        assert_eq!(spans[0].style, finder.number);
+        assert_eq!(spans[1].style, finder.letter);
+        assert_eq!(spans[2].style, finder.separator);
+    }
+
+    #[test]
+    fn groups_inside_an_ipv6_address_are_not_a_mac() {
+        assert_eq!(matched_range("2001:db8:aa:bb:cc:dd:ee:ff"), None);
+        assert_eq!(matched_range("fe80::aa:bb:cc:dd:ee:ff"), None);
+    }
+
+    #[test]
+    fn mixed_separators_do_not_match() {
+        assert_eq!(matched_range("aa:bb-cc:dd-ee:ff"), None);
+    }
+
+    #[test]
+    fn times_and_versions_do_not_match() {
+        assert_eq!(matched_range("12:34:56 v1.2.3"), None);
+    }
+}
@@ -8,24 +8,44 @@
 
 use ::regex::{Regex, RegexBuilder};
 
+pub(crate) mod cloud_resource;
 pub(crate) mod date_dash;
+pub(crate) mod date_name;
 pub(crate) mod date_time;
+pub(crate) mod diff;
 pub(crate) mod duration;
 pub(crate) mod email;
+pub(crate) mod epoch;
+pub(crate) mod go_panic;
+pub(crate) mod hash;
+pub(crate) mod hostname;
 pub(crate) mod ip_v4;
 pub(crate) mod ip_v6;
+pub(crate) mod js_dotnet_stack;
 pub(crate) mod json;
 pub(crate) mod jvm_stack;
 pub(crate) mod key_value;
 pub(crate) mod keyword;
+pub(crate) mod mac_address;
 pub(crate) mod number;
+pub(crate) mod permission;
+pub(crate) mod placeholder;
 pub(crate) mod pointer;
+pub(crate) mod python_traceback;
 pub(crate) mod quote;
 pub(crate) mod regex;
+pub(crate) mod rust_backtrace;
+pub(crate) mod size;
+pub(crate) mod sql;
+pub(crate) mod syslog;
+pub(crate) mod token;
+pub(crate) mod trace_id;
 pub(crate) mod unix_path;
 pub(crate) mod unix_process;
 pub(crate) mod url;
 pub(crate) mod uuid;
+pub(crate) mod windows_path;
+pub(crate) mod xml;
 
 /// Hardcoded finder regexes are byte-mode: `\w`/`\d`/`\s`/`\b` stay ASCII and
 /// skip the Unicode tables in the hot path.
@@ -0,0 +1,255 @@
+use super::build_regex;
+use regex::Regex;
+
+use crate::core::config::PermissionConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+#[derive(Debug, Clone)]
+pub(crate) struct PermissionFinder {
+    regex: Regex,
+    file_type: StyleId,
+    read: StyleId,
+    write: StyleId,
+    execute: StyleId,
+    special: StyleId,
+    world_writable: StyleId,
+    none: StyleId,
+}
+
+impl PermissionFinder {
+    pub fn new(config: PermissionConfig, palette: &mut Palette) -> Self {
+        // An octal mode is just a number unless something says it is a mode:
+        // `chmod 755`, `mode=0644`, `umask 022`. Only `0o755` stands on its
+        // own; `0123` is as likely an order number or a zip code.
+        let pattern = r"(?x)
+            (?P<symbolic>[-dlcbpsD][r-][w-][xsS-][r-][w-][xsS-][r-][w-][xtT-][.+@]?)
+          | (?P<prefix>0o)(?P<octal>[0-7]{3,4})
+          | (?:chmod\x20+(?:-R\x20+)?|(?P<umask>umask)\x20+|(?:mode|perms?|permissions?)[=:]\x20*)
+            (?P<zero>0)?(?P<bare>[0-7]{3,4})
+        ";
+
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            file_type: palette.intern(config.file_type),
+            read: palette.intern(config.read),
+            write: palette.intern(config.write),
+            execute: palette.intern(config.execute),
+            special: palette.intern(config.special),
+            world_writable: palette.intern(config.world_writable),
+            none: palette.intern(config.none),
+        }
+    }
+
+    /// `drwxr-xr-x`: the type, then owner, group and other triplets, and an
+    /// optional ACL or `SELinux` marker.
+    fn push_symbolic(&self, mode: &str, offset: usize, collector: &mut Collector) {
+        for (i, b) in mode.bytes().enumerate() {
+            let style = match (i, b) {
+                (_, b'-') => self.none,
+                (0, _) => self.file_type,
+                (1..=9, b'r') => self.read,
+                (8, b'w') => self.world_writable,
+                (1..=9, b'w') => self.write,
+                (1..=9, b'x') => self.execute,
+                (1..=9, _) => self.special,
+                _ => self.none,
+            };
+            collector.push(offset + i, offset + i + 1, style);
+        }
+    }
+
+    /// `644`, `4755`: an optional setuid/setgid/sticky digit, then owner,
+    /// group and other digits, each styled by the most permissive bit it
+    /// grants. A umask lists the bits it takes away, so its owner, group and
+    /// other digits are styled by what they leave: `umask 000` is the one
+    /// that makes new files world-writable.
+    fn push_octal(&self, digits: &str, offset: usize, umask: bool, collector: &mut Collector) {
+        let special_digits = digits.len() - 3;
+        for (i, b) in digits.bytes().enumerate() {
+            let bits = match b - b'0' {
+                bits if umask && i >= special_digits => 7 - bits,
+                bits => bits,
+            };
+            let style = if i < special_digits {
+                if bits == 0 { self.none } else { self.special }
+            } else if bits & 2 != 0 {
+                if i == digits.len() - 1 {
+                    self.world_writable
+                } else {
+                    self.write
+                }
+            } else if bits & 1 != 0 {
+                self.execute
+            } else if bits & 4 != 0 {
+                self.read
+            } else {
+                self.none
+            };
+            collector.push(offset + i, offset + i + 1, style);
+        }
+    }
+}
+
+/// Whether the match is a token of its own rather than part of a longer word,
+/// number, version or dash run.
+fn is_standalone(bytes: &[u8], start: usize, end: usize) -> bool {
+    let glued = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'-';
+    let before = start.checked_sub(1).map(|i| bytes[i]);
+    let after = bytes.get(end).copied();
+    let decimal_after = after == Some(b'.') && bytes.get(end + 1).is_some_and(u8::is_ascii_digit);
+
+    !before.is_some_and(|b| glued(b) || b == b'.') && !after.is_some_and(glued) && !decimal_after
+}
+
+impl Finder for PermissionFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+        for caps in self.regex.captures_iter(input) {
+            let whole = caps.get(0).unwrap();
+            if !is_standalone(bytes, whole.start(), whole.end()) {
+                continue;
+            }
+
+            if let Some(symbolic) = caps.name("symbolic") {
+                // A run of ten dashes is a rule, not a mode without permissions.
+                if symbolic.as_str().bytes().all(|b| b == b'-') {
+                    continue;
+                }
+                self.push_symbolic(symbolic.as_str(), symbolic.start(), collector);
+            } else if let Some(octal) = caps.name("octal") {
+                let prefix = caps.name("prefix").unwrap();
+                collector.push(prefix.start(), prefix.end(), self.none);
+                self.push_octal(octal.as_str(), octal.start(), false, collector);
+            } else if let Some(bare) = caps.name("bare") {
+                if let Some(zero) = caps.name("zero") {
+                    collector.push(zero.start(), zero.end(), self.none);
+                }
+                let umask = caps.name("umask").is_some();
+                self.push_octal(bare.as_str(), bare.start(), umask, collector);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> PermissionFinder {
+        PermissionFinder::new(
+            PermissionConfig {
+                file_type: Style::new().fg(Color::Blue),
+                read: Style::new().fg(Color::Yellow),
+                write: Style::new().fg(Color::Red),
+                execute: Style::new().fg(Color::Green),
+                special: Style::new().fg(Color::Magenta),
+                world_writable: Style::new().fg(Color::White).on(Color::Red),
+                none: Style::new().faint(),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    fn styles(input: &str) -> Vec<StyleId> {
+        let mut collector = Collector::new();
+        make_finder().find_spans(input, &mut collector);
+        collector.into_spans().iter().map(|s| s.style).collect()
+    }
+
+    #[test]
+    fn symbolic_modes() {
+        let finder = make_finder();
+        let texts = span_texts("drwxr-xr-x 2 root root 4096 .", &finder);
+        assert_eq!(texts, ["d", "r", "w", "x", "r", "-", "x", "r", "-", "x"]);
+        let texts = span_texts("-rw-r----- 1 app app", &finder);
+        assert_eq!(texts, ["-", "r", "w", "-", "r", "-----"]);
+    }
+
+    #[test]
+    fn symbolic_styles() {
+        let f = make_finder();
+        assert_eq!(
+            styles("-rwsr-xrwt"),
+            [
+                f.none,
+                f.read,
+                f.write,
+                f.special,
+                f.read,
+                f.none,
+                f.execute,
+                f.read,
+                f.world_writable,
+                f.special
+            ]
+        );
+    }
+
+    #[test]
+    fn acl_marker_is_part_of_the_mode() {
+        let texts = span_texts("-rw-r--r--+ 1 root", &make_finder());
+        assert_eq!(texts.concat(), "-rw-r--r--+");
+    }
+
+    #[test]
+    fn octal_modes() {
+        let f = make_finder();
+        assert_eq!(span_texts("mode=0644 set", &f), ["0", "6", "44"]);
+        // Adjacent digits with the same style coalesce into one span.
+        assert_eq!(styles("chmod 0644"), [f.none, f.write, f.read]);
+        assert_eq!(styles("0o750"), [f.none, f.write, f.execute, f.none]);
+        assert_eq!(styles("chmod 04755"), [f.none, f.special, f.write, f.execute]);
+        assert_eq!(styles("perm=0666"), [f.none, f.write, f.world_writable]);
+        assert_eq!(span_texts("umask 022", &f).concat(), "022");
+    }
+
+    #[test]
+    fn umask_digits_are_styled_by_what_they_leave() {
+        let f = make_finder();
+        // 022 leaves rwx, r-x, r-x; 077 leaves rwx, ---, ---.
+        assert_eq!(styles("umask 022"), [f.write, f.execute]);
+        assert_eq!(styles("umask 077"), [f.write, f.none]);
+        assert_eq!(styles("umask 0027"), [f.none, f.write, f.execute, f.none]);
+        assert_eq!(styles("umask 000"), [f.write, f.world_writable]);
+    }
+
+    #[test]
+    fn bare_modes_need_context() {
+        let finder = make_finder();
+        assert_eq!(span_texts("chmod 777 /tmp/x", &finder).concat(), "777");
+        assert_eq!(span_texts("chmod -R 750 dir", &finder).concat(), "750");
+        assert_eq!(span_texts("mode=640", &finder).concat(), "640");
+        assert!(span_texts("served 644 requests", &finder).is_empty());
+    }
+
+    #[test]
+    fn zero_prefixed_numbers_need_context() {
+        let finder = make_finder();
+        for input in ["0001", "0123", "02134", "order 0123 code 0001 zip 02134"] {
+            assert!(span_texts(input, &finder).is_empty(), "{input}");
+        }
+    }
+
+    #[test]
+    fn non_modes_do_not_match() {
+        let finder = make_finder();
+        for input in [
+            "----------",
+            "------------------",
+            "10644",
+            "0648",
+            "v1.0644",
+            "0644.5",
+            "x-rw-r--r--",
+            "-rw-r--r--x",
+        ] {
+            assert!(span_texts(input, &finder).is_empty(), "{input}");
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use super::build_regex;
+use regex::{Captures, Regex};
+
+use crate::core::config::PlaceholderConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// The opening, name and closing groups of each branch. The regex crate does
+/// not allow a group name twice, so each branch has its own.
+const BRANCHES: [(&str, &str, Option<&str>); 5] = [
+    ("braced_open", "braced", Some("braced_close")),
+    ("template_open", "template", Some("template_close")),
+    ("format_open", "format", Some("format_close")),
+    ("percent_open", "percent", Some("percent_close")),
+    ("dollar", "shell", None),
+];
+
+#[derive(Debug, Clone)]
+pub(crate) struct PlaceholderFinder {
+    regex: Regex,
+    sigil: StyleId,
+    name: StyleId,
+}
+
+impl PlaceholderFinder {
+    pub fn new(config: PlaceholderConfig, palette: &mut Palette) -> Self {
+        // Default values (`${VAR:-x}`) and format specs (`{n:>8}`) sit between
+        // the name and the closing brace and are left unstyled.
+        let pattern = r"(?x)
+            (?P<braced_open>\$\{) (?P<braced>[A-Za-z_]\w*) (?::?[-=?+](?u:[^}])*)? (?P<braced_close>\})
+          | (?P<template_open>\{\{-?\x20*) (?P<template>(?u:[^{}\s-])(?:(?u:[^{}])*(?u:[^{}\s-]))?) (?P<template_close>\x20*-?\}\})
+          | (?P<format_open>\{) (?P<format>[A-Za-z_][\w.]*) (?::(?u:[^{}\s])*)? (?P<format_close>\})
+          | (?P<percent_open>%) (?P<percent>[A-Za-z_][\w()]+) (?P<percent_close>%)
+          | (?P<dollar>\$) (?P<shell>[A-Za-z_]\w*)
+        ";
+
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            sigil: palette.intern(config.sigil),
+            name: palette.intern(config.name),
+        }
+    }
+
+    fn push_placeholder(&self, caps: &Captures, collector: &mut Collector) {
+        for (open, name, close) in BRANCHES {
+            let Some(name) = caps.name(name) else {
+                continue;
+            };
+            let open = caps.name(open).unwrap();
+            collector.push(open.start(), open.end(), self.sigil);
+            collector.push(name.start(), name.end(), self.name);
+            if let Some(close) = close.and_then(|close| caps.name(close)) {
+                collector.push(close.start(), close.end(), self.sigil);
+            }
+            return;
+        }
+    }
+}
+
+impl Finder for PlaceholderFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if !input.bytes().any(|b| matches!(b, b'$' | b'%' | b'{')) {
+            return;
+        }
+
+        let bytes = input.as_bytes();
+        for caps in self.regex.captures_iter(input) {
+            let whole = caps.get(0).unwrap();
+            // `US$AMOUNT` and `50%off%` are words, and `%E2%` is URL encoding.
+            let glued = whole.start() > 0 && bytes[whole.start() - 1].is_ascii_alphanumeric();
+            let url_encoded = caps
+                .name("percent")
+                .is_some_and(|m| m.len() == 2 && m.as_str().bytes().all(|b| b.is_ascii_hexdigit()));
+            if glued || url_encoded {
+                continue;
+            }
+
+            self.push_placeholder(&caps, collector);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> PlaceholderFinder {
+        PlaceholderFinder::new(
+            PlaceholderConfig {
+                sigil: Style::new().fg(Color::Yellow),
+                name: Style::new().fg(Color::Magenta),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn shell_variables() {
+        let finder = make_finder();
+        assert_eq!(span_texts("cd $HOME/app", &finder), ["$", "HOME"]);
+        assert_eq!(span_texts("url=${DATABASE_URL}", &finder), ["${", "DATABASE_URL", "}"]);
+        assert_eq!(span_texts("${PORT:-8080}", &finder), ["${", "PORT", "}"]);
+    }
+
+    #[test]
+    fn windows_variables() {
+        assert_eq!(span_texts(r"set %PATH%;C:\bin", &make_finder()), ["%", "PATH", "%"]);
+    }
+
+    #[test]
+    fn template_expressions() {
+        let finder = make_finder();
+        assert_eq!(
+            span_texts("image: {{ .Values.image }}", &finder),
+            ["{{ ", ".Values.image", " }}"]
+        );
+        assert_eq!(
+            span_texts("{{- .Release.Name -}}", &finder),
+            ["{{- ", ".Release.Name", " -}}"]
+        );
+    }
+
+    #[test]
+    fn format_fields() {
+        let finder = make_finder();
+        assert_eq!(span_texts("/users/{user_id}", &finder), ["{", "user_id", "}"]);
+        assert_eq!(span_texts("{elapsed:>8}", &finder), ["{", "elapsed", "}"]);
+    }
+
+    #[test]
+    fn non_placeholders_do_not_match() {
+        let finder = make_finder();
+        for input in [
+            "costs $5",
+            "US$AMOUNT",
+            "91.5% on disk, 93% later",
+            "progress %d%%",
+            "caf%C3%A9",
+            r#"{"level":"info"}"#,
+            "{}",
+            "{ spaced }",
+        ] {
+            assert!(span_texts(input, &finder).is_empty(), "{input}");
+        }
+    }
+}
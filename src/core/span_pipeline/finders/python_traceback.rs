@@ -0,0 +1,220 @@
+use super::build_regex;
+use memchr::{memchr, memchr2};
+use regex::Regex;
+
+use crate::core::config::PythonTracebackConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+#[derive(Debug, Clone)]
+pub(crate) struct PythonTracebackFinder {
+    marker_regex: Regex,
+    frame_regex: Regex,
+    exception_regex: Regex,
+    caret_regex: Regex,
+    marker: StyleId,
+    frame: StyleId,
+    file: StyleId,
+    line_number: StyleId,
+    function: StyleId,
+    module: StyleId,
+    exception: StyleId,
+}
+
+impl PythonTracebackFinder {
+    pub fn new(config: PythonTracebackConfig, palette: &mut Palette) -> Self {
+        // The traceback header and the two chained-exception separators.
+        let marker_pattern = r"(?xm)
+            ^\s*
+            (?P<marker>
+                Traceback\x20\(most\x20recent\x20call\x20last\):
+              | During\x20handling\x20of\x20the\x20above\x20exception,\x20another\x20exception\x20occurred:
+              | The\x20above\x20exception\x20was\x20the\x20direct\x20cause\x20of\x20the\x20following\x20exception:
+            )
+        ";
+        let marker_regex = build_regex(marker_pattern);
+
+        let frame_pattern = r#"(?xm)
+            ^\s+
+            (?P<file_kw>File\x20")
+            (?P<file>(?u:[^"\n])+)
+            (?P<line_kw>",\x20line\x20)
+            (?P<line>\d+)
+            (?:(?P<in_kw>,\x20in\x20)(?P<function><?[A-Za-z_][A-Za-z0-9_.]*>?))?
+        "#;
+        let frame_regex = build_regex(frame_pattern);
+
+        // The exception line starts at column 0 once the frames are done. A
+        // bare `Error:` is too common in ordinary logs, so the class needs a
+        // prefix — except for `Exception` itself.
+        let exception_pattern = r"(?xm)
+            ^
+            (?P<module>(?:[a-z_][a-zA-Z0-9_]*\.)*)
+            (?P<class>
+                [A-Z][A-Za-z0-9_]*(?:Error|Exception|Warning|Interrupt|Exit|Iteration)
+              | Exception
+            )
+            (?:(?P<colon>:)|$)
+        ";
+        let exception_regex = build_regex(exception_pattern);
+
+        // Python 3.11+ underlines the failing expression with `~` and `^`.
+        let caret_pattern = r"(?m)^\s+(?P<caret>[~^]*\^[~^]*)\s*$";
+        let caret_regex = build_regex(caret_pattern);
+
+        Self {
+            marker_regex,
+            frame_regex,
+            exception_regex,
+            caret_regex,
+            marker: palette.intern(config.marker),
+            frame: palette.intern(config.frame),
+            file: palette.intern(config.file),
+            line_number: palette.intern(config.line_number),
+            function: palette.intern(config.function),
+            module: palette.intern(config.module),
+            exception: palette.intern(config.exception),
+        }
+    }
+}
+
+/// Whether `input` mentions a word an exception class name can end with.
+fn has_exception_word(input: &str) -> bool {
+    ["Error", "Exception", "Warning", "Interrupt", "Exit", "Iteration"]
+        .iter()
+        .any(|w| input.contains(w))
+}
+
+impl Finder for PythonTracebackFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+
+        if memchr(b':', bytes).is_some() {
+            for caps in self.marker_regex.captures_iter(input) {
+                let marker = caps.name("marker").unwrap();
+                collector.push(marker.start(), marker.end(), self.marker);
+            }
+        }
+
+        if memchr(b'"', bytes).is_some() {
+            for caps in self.frame_regex.captures_iter(input) {
+                let file_kw = caps.name("file_kw").unwrap();
+                let file = caps.name("file").unwrap();
+                let line_kw = caps.name("line_kw").unwrap();
+                let line = caps.name("line").unwrap();
+                collector.push(file_kw.start(), file_kw.end(), self.frame);
+                collector.push(file.start(), file.end(), self.file);
+                collector.push(line_kw.start(), line_kw.end(), self.frame);
+                collector.push(line.start(), line.end(), self.line_number);
+
+                if let (Some(in_kw), Some(function)) = (caps.name("in_kw"), caps.name("function")) {
+                    collector.push(in_kw.start(), in_kw.end(), self.frame);
+                    collector.push(function.start(), function.end(), self.function);
+                }
+            }
+        }
+
+        if has_exception_word(input) {
+            for caps in self.exception_regex.captures_iter(input) {
+                let module = caps.name("module").unwrap();
+                let class = caps.name("class").unwrap();
+                if !module.is_empty() {
+                    collector.push(module.start(), module.end(), self.module);
+                }
+                collector.push(class.start(), class.end(), self.exception);
+                if let Some(colon) = caps.name("colon") {
+                    collector.push(colon.start(), colon.end(), self.frame);
+                }
+            }
+        }
+
+        if memchr2(b'^', b'~', bytes).is_some() {
+            for caps in self.caret_regex.captures_iter(input) {
+                let caret = caps.name("caret").unwrap();
+                collector.push(caret.start(), caret.end(), self.frame);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> PythonTracebackFinder {
+        PythonTracebackFinder::new(
+            PythonTracebackConfig {
+                marker: Style::new().bold(),
+                frame: Style::new().fg(Color::Red).faint(),
+                file: Style::new().fg(Color::Yellow),
+                line_number: Style::new().fg(Color::Cyan),
+                function: Style::new().fg(Color::Green),
+                module: Style::new().fg(Color::Red).faint(),
+                exception: Style::new().fg(Color::Red),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn traceback_header_is_a_marker() {
+        assert_eq!(
+            span_texts("Traceback (most recent call last):", &make_finder()),
+            ["Traceback (most recent call last):"]
+        );
+    }
+
+    #[test]
+    fn frame_splits_file_line_and_function() {
+        let texts = span_texts(r#"  File "/app/x.py", line 42, in handler"#, &make_finder());
+        assert_eq!(texts, ["File \"", "/app/x.py", "\", line ", "42", ", in ", "handler"]);
+    }
+
+    #[test]
+    fn frame_in_module_scope() {
+        let texts = span_texts(r#"  File "<stdin>", line 1, in <module>"#, &make_finder());
+        assert_eq!(texts.last(), Some(&"<module>"));
+    }
+
+    #[test]
+    fn exception_line_with_module_and_message() {
+        let input = "requests.exceptions.ConnectionError: Max retries exceeded";
+        let finder = make_finder();
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+        let spans = collector.into_spans();
+        let texts: Vec<&str> = spans.iter().map(|s| &input[s.start..s.end]).collect();
+
+        assert_eq!(texts, ["requests.exceptions.", "ConnectionError", ":"]);
+        assert_eq!(spans[0].style, finder.module);
+        assert_eq!(spans[1].style, finder.exception);
+    }
+
+    #[test]
+    fn bare_exception_classes() {
+        assert_eq!(span_texts("KeyboardInterrupt", &make_finder()), ["KeyboardInterrupt"]);
+        assert_eq!(span_texts("Exception: boom", &make_finder()), ["Exception", ":"]);
+    }
+
+    #[test]
+    fn chained_exception_markers() {
+        let during = "During handling of the above exception, another exception occurred:";
+        let cause = "The above exception was the direct cause of the following exception:";
+        assert_eq!(span_texts(during, &make_finder()), [during]);
+        assert_eq!(span_texts(cause, &make_finder()), [cause]);
+    }
+
+    #[test]
+    fn caret_underline_is_styled() {
+        assert_eq!(span_texts("    ~~~~~^^^^^", &make_finder()), ["~~~~~^^^^^"]);
+    }
+
+    #[test]
+    fn plain_error_prefix_and_indented_exceptions_do_not_match() {
+        assert!(span_texts("Error: disk full", &make_finder()).is_empty());
+        assert!(span_texts("  raised ValueError: nope", &make_finder()).is_empty());
+    }
+}
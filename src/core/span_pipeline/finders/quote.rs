@@ -22,7 +22,10 @@ impl QuoteFinder {
 
 impl Finder for QuoteFinder {
     fn find_spans(&self, input: &str, collector: &mut Collector) {
-        let positions: Vec<usize> = memchr_iter(self.quote_token, input.as_bytes()).collect();
+        let bytes = input.as_bytes();
+        let positions: Vec<usize> = memchr_iter(self.quote_token, bytes)
+            .filter(|&i| !is_escaped(bytes, i))
+            .collect();
 
         if positions.len() < 2 || !positions.len().is_multiple_of(2) {
             return;
@@ -35,6 +38,12 @@ impl Finder for QuoteFinder {
     }
 }
 
+/// Whether the byte at `i` follows an odd number of backslashes, as in
+/// `msg="say \"hi\""`.
+fn is_escaped(bytes: &[u8], i: usize) -> bool {
+    bytes[..i].iter().rev().take_while(|&&b| b == b'\\').count() % 2 == 1
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::span_texts;
@@ -62,6 +71,12 @@ mod tests {
         assert!(span_texts(r#"hello "world end"#, &make_finder(b'"')).is_empty());
     }
 
+    #[test]
+    fn escaped_quotes_do_not_close_the_region() {
+        let texts = span_texts(r#"msg="say \"hi\"" next \\"x""#, &make_finder(b'"'));
+        assert_eq!(texts, [r#""say \"hi\"""#, r#""x""#]);
+    }
+
     #[test]
     fn multiple_quoted_regions() {
         let texts = span_texts(r#""hello" and "world""#, &make_finder(b'"'));
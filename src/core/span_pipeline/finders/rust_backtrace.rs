@@ -0,0 +1,344 @@
+use super::build_regex;
+use memchr::memchr;
+use regex::Regex;
+
+use crate::core::config::RustBacktraceConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Frames from the standard library and async runtimes; dimmed so the
+/// application's own frames stand out.
+const RUNTIME_PREFIXES: &[&str] = &[
+    "std::",
+    "core::",
+    "alloc::",
+    "tokio::",
+    "futures::",
+    "futures_core::",
+    "futures_util::",
+];
+
+/// Runtime frames that are bare symbols rather than paths.
+const RUNTIME_SYMBOLS: &[&str] = &[
+    "rust_begin_unwind",
+    "__rust_begin_short_backtrace",
+    "__rust_end_short_backtrace",
+    "__rust_try",
+    "main",
+    "_start",
+    "__libc_start_main",
+    "__libc_start_call_main",
+];
+
+#[derive(Debug, Clone)]
+pub(crate) struct RustBacktraceFinder {
+    panic_regex: Regex,
+    marker_regex: Regex,
+    frame_regex: Regex,
+    location_regex: Regex,
+    thread: StyleId,
+    marker: StyleId,
+    frame: StyleId,
+    file: StyleId,
+    line_number: StyleId,
+    frame_index: StyleId,
+    path: StyleId,
+    separator: StyleId,
+    runtime: StyleId,
+}
+
+impl RustBacktraceFinder {
+    pub fn new(config: RustBacktraceConfig, palette: &mut Palette) -> Self {
+        // Since Rust 1.73 the message follows on the next line; older
+        // releases quote it before the location: `panicked at 'msg', src/…`.
+        let panic_pattern = r"(?xm)
+            ^
+            (?P<thread_kw>thread\x20)
+            (?P<thread>'(?u:[^'\n])*')
+            \x20(?P<panicked>panicked\x20at)\x20
+            (?:'(?u:[^\n])*?',\x20)?
+            (?P<file>(?u:[^\s:'])+\.rs)
+            (?P<line_colon>:)(?P<line>\d+)
+            (?P<col_colon>:)(?P<col>\d+)
+            (?P<colon>:)?
+        ";
+        let panic_regex = build_regex(panic_pattern);
+
+        let marker_regex = build_regex(r"(?m)^(?P<marker>stack\x20backtrace:)[\x20\t]*$");
+
+        // `  12: mycrate::module::func`, optionally with the pre-1.50
+        // `0x55d1c3 - ` address in front of the symbol.
+        let frame_pattern = r"(?xm)
+            ^\s+
+            (?P<index>\d+)(?P<index_colon>:)\x20+
+            (?P<address>0x[0-9a-fA-F]+\x20-\x20)?
+            (?P<symbol>[<A-Za-z_](?u:[^\n])*?)
+            [\x20\t]*$
+        ";
+        let frame_regex = build_regex(frame_pattern);
+
+        let location_pattern = r"(?xm)
+            ^\s+
+            (?P<at>at\x20)
+            (?P<file>(?u:[^\s:])+\.rs)
+            (?P<line_colon>:)(?P<line>\d+)
+            (?:(?P<col_colon>:)(?P<col>\d+))?
+            [\x20\t]*$
+        ";
+        let location_regex = build_regex(location_pattern);
+
+        Self {
+            panic_regex,
+            marker_regex,
+            frame_regex,
+            location_regex,
+            thread: palette.intern(config.thread),
+            marker: palette.intern(config.marker),
+            frame: palette.intern(config.frame),
+            file: palette.intern(config.file),
+            line_number: palette.intern(config.line_number),
+            frame_index: palette.intern(config.frame_index),
+            path: palette.intern(config.path),
+            separator: palette.intern(config.separator),
+            runtime: palette.intern(config.runtime),
+        }
+    }
+
+    /// Styles a crate path: segments as `path`, each `::` as `separator`.
+    fn push_symbol(&self, symbol: &str, offset: usize, collector: &mut Collector) {
+        let mut pos = 0;
+        for (i, segment) in symbol.split("::").enumerate() {
+            if i > 0 {
+                collector.push(offset + pos, offset + pos + 2, self.separator);
+                pos += 2;
+            }
+            if !segment.is_empty() {
+                collector.push(offset + pos, offset + pos + segment.len(), self.path);
+            }
+            pos += segment.len();
+        }
+    }
+}
+
+/// A frame symbol is a path (`a::b`), a qualified path (`<A as B>::c`) or a
+/// single identifier; anything else after `N: ` is more likely a numbered list.
+fn is_symbol(symbol: &str) -> bool {
+    symbol.contains("::") || symbol.starts_with('<') || symbol.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+fn is_runtime_symbol(symbol: &str) -> bool {
+    let path = symbol.trim_start_matches('<');
+    RUNTIME_PREFIXES.iter().any(|prefix| path.starts_with(prefix)) || RUNTIME_SYMBOLS.contains(&symbol)
+}
+
+/// Sources of the standard library and of crates.io dependencies.
+fn is_runtime_file(file: &str) -> bool {
+    file.starts_with("/rustc/") || file.contains("/.cargo/registry/") || file.contains("/library/std/")
+}
+
+impl Finder for RustBacktraceFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if memchr(b':', input.as_bytes()).is_none() {
+            return;
+        }
+
+        if input.contains("panicked") {
+            for caps in self.panic_regex.captures_iter(input) {
+                let thread_kw = caps.name("thread_kw").unwrap();
+                let thread = caps.name("thread").unwrap();
+                let panicked = caps.name("panicked").unwrap();
+                collector.push(thread_kw.start(), thread_kw.end(), self.frame);
+                collector.push(thread.start(), thread.end(), self.thread);
+                collector.push(panicked.start(), panicked.end(), self.marker);
+
+                for (name, style) in [
+                    ("file", self.file),
+                    ("line_colon", self.frame),
+                    ("line", self.line_number),
+                    ("col_colon", self.frame),
+                    ("col", self.line_number),
+                    ("colon", self.frame),
+                ] {
+                    if let Some(m) = caps.name(name) {
+                        collector.push(m.start(), m.end(), style);
+                    }
+                }
+            }
+        }
+
+        if input.contains("stack backtrace:") {
+            for caps in self.marker_regex.captures_iter(input) {
+                let marker = caps.name("marker").unwrap();
+                collector.push(marker.start(), marker.end(), self.marker);
+            }
+        }
+
+        for caps in self.frame_regex.captures_iter(input) {
+            let symbol = caps.name("symbol").unwrap();
+            if !is_symbol(symbol.as_str()) {
+                continue;
+            }
+
+            let index = caps.name("index").unwrap();
+            let index_colon = caps.name("index_colon").unwrap();
+            collector.push(index.start(), index.end(), self.frame_index);
+            collector.push(index_colon.start(), index_colon.end(), self.frame);
+            if let Some(address) = caps.name("address") {
+                collector.push(address.start(), address.end(), self.frame);
+            }
+
+            if is_runtime_symbol(symbol.as_str()) {
+                collector.push(symbol.start(), symbol.end(), self.runtime);
+            } else {
+                self.push_symbol(symbol.as_str(), symbol.start(), collector);
+            }
+        }
+
+        if input.contains(".rs:") {
+            for caps in self.location_regex.captures_iter(input) {
+                let at = caps.name("at").unwrap();
+                let file = caps.name("file").unwrap();
+
+                if is_runtime_file(file.as_str()) {
+                    collector.push(at.start(), caps.get(0).unwrap().end(), self.runtime);
+                    continue;
+                }
+
+                collector.push(at.start(), at.end(), self.frame);
+                collector.push(file.start(), file.end(), self.file);
+                for (name, style) in [
+                    ("line_colon", self.frame),
+                    ("line", self.line_number),
+                    ("col_colon", self.frame),
+                    ("col", self.line_number),
+                ] {
+                    if let Some(m) = caps.name(name) {
+                        collector.push(m.start(), m.end(), style);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> RustBacktraceFinder {
+        RustBacktraceFinder::new(
+            RustBacktraceConfig {
+                thread: Style::new().fg(Color::Magenta),
+                marker: Style::new().fg(Color::Red).bold(),
+                frame: Style::new().fg(Color::Red).faint(),
+                file: Style::new().fg(Color::Yellow),
+                line_number: Style::new().fg(Color::Cyan),
+                frame_index: Style::new().fg(Color::Blue),
+                path: Style::new().fg(Color::Green),
+                separator: Style::new().fg(Color::White),
+                runtime: Style::new().faint(),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    fn styled(input: &str) -> Vec<(&str, StyleId)> {
+        let mut collector = Collector::new();
+        make_finder().find_spans(input, &mut collector);
+        collector
+            .into_spans()
+            .iter()
+            .map(|s| (&input[s.start..s.end], s.style))
+            .collect()
+    }
+
+    #[test]
+    fn panic_header_splits_thread_and_location() {
+        let texts = span_texts("thread 'main' panicked at src/foo.rs:12:5:", &make_finder());
+        assert_eq!(
+            texts,
+            [
+                "thread ",
+                "'main'",
+                "panicked at",
+                "src/foo.rs",
+                ":",
+                "12",
+                ":",
+                "5",
+                ":"
+            ]
+        );
+    }
+
+    #[test]
+    fn legacy_panic_header_with_quoted_message() {
+        let texts = span_texts(
+            "thread '<unnamed>' panicked at 'called `Option::unwrap()`', src/main.rs:2:5",
+            &make_finder(),
+        );
+        assert_eq!(
+            texts,
+            [
+                "thread ",
+                "'<unnamed>'",
+                "panicked at",
+                "src/main.rs",
+                ":",
+                "2",
+                ":",
+                "5"
+            ]
+        );
+    }
+
+    #[test]
+    fn backtrace_marker() {
+        assert_eq!(span_texts("stack backtrace:", &make_finder()), ["stack backtrace:"]);
+    }
+
+    #[test]
+    fn application_frame_splits_path_segments() {
+        let texts = span_texts("  12: mycrate::module::func", &make_finder());
+        assert_eq!(texts, ["12", ":", "mycrate", "::", "module", "::", "func"]);
+    }
+
+    #[test]
+    fn runtime_frames_are_dimmed_whole() {
+        let finder = make_finder();
+        for input in [
+            "   1: core::panicking::panic_fmt",
+            "  13: <alloc::boxed::Box<F,A> as core::ops::function::FnOnce<Args>>::call_once",
+            "   7: tokio::runtime::task::raw::poll",
+            "   0: rust_begin_unwind",
+        ] {
+            let spans = styled(input);
+            assert_eq!(spans.len(), 3, "{input}");
+            assert_eq!(spans[2].1, finder.runtime, "{input}");
+        }
+    }
+
+    #[test]
+    fn at_location_of_application_frame() {
+        let texts = span_texts("             at ./src/lib.rs:33:9", &make_finder());
+        assert_eq!(texts, ["at ", "./src/lib.rs", ":", "33", ":", "9"]);
+    }
+
+    #[test]
+    fn at_location_inside_std_is_dimmed() {
+        let input =
+            "             at /rustc/90b35a6239c3d8bdabc530a6a0816f7ff89a0aaf/library/std/src/panicking.rs:652:5";
+        let spans = styled(input);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].1, make_finder().runtime);
+    }
+
+    #[test]
+    fn numbered_prose_is_not_a_frame() {
+        assert!(span_texts("  1: check the config file", &make_finder()).is_empty());
+        assert!(span_texts("we met at src/lib.rs:3", &make_finder()).is_empty());
+    }
+}
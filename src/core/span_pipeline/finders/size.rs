@@ -0,0 +1,129 @@
+use super::build_regex;
+use regex::Regex;
+
+use crate::core::config::SizeConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+#[derive(Debug, Clone)]
+pub(crate) struct SizeFinder {
+    regex: Regex,
+    value: StyleId,
+    separator: StyleId,
+    unit: StyleId,
+}
+
+impl SizeFinder {
+    pub fn new(config: SizeConfig, palette: &mut Palette) -> Self {
+        // Units are case-sensitive so `5m` (a duration) and `3 b` stay out;
+        // a single space may sit between value and unit (`1.5 GB`).
+        let pattern = r"(?x)
+            \b
+            (?P<integer>\d+)                    # integer part
+            (?:(?P<dot>\.)(?P<fraction>\d+))?   # optional fractional part
+            \x20?
+            (?P<unit>
+                (?:[KMGTPE]iB|[kKMGTPE]B|B|bytes|byte)(?:/sec|/s|ps)?\b   # sizes and byte rates
+              | [kKMGT]?bps\b                                             # bit rates
+              | (?:req|reqs|requests|ops|msg|msgs|events|rows|queries|pkts|packets)/(?:sec|min|s)\b
+              | %
+            )
+        ";
+
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            value: palette.intern(config.value),
+            separator: palette.intern(config.separator),
+            unit: palette.intern(config.unit),
+        }
+    }
+}
+
+impl Finder for SizeFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        // Every unit contains a `B`, `b`, `/` or `%`.
+        if !input.bytes().any(|b| matches!(b, b'B' | b'b' | b'/' | b'%')) {
+            return;
+        }
+
+        for caps in self.regex.captures_iter(input) {
+            let integer = caps.name("integer").unwrap();
+            collector.push(integer.start(), integer.end(), self.value);
+
+            if let Some(dot) = caps.name("dot") {
+                let fraction = caps.name("fraction").unwrap();
+                collector.push(dot.start(), dot.end(), self.separator);
+                collector.push(fraction.start(), fraction.end(), self.value);
+            }
+
+            let unit = caps.name("unit").unwrap();
+            collector.push(unit.start(), unit.end(), self.unit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> SizeFinder {
+        SizeFinder::new(
+            SizeConfig {
+                value: Style::new().fg(Color::Cyan),
+                separator: Style::new().fg(Color::Red),
+                unit: Style::new().fg(Color::Magenta),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn finds_binary_and_decimal_sizes() {
+        let texts = span_texts("heap 512KiB of 1.5 GB, 3MB free", &make_finder());
+        assert_eq!(texts, ["512", "KiB", "1", ".", "5", "GB", "3", "MB"]);
+    }
+
+    #[test]
+    fn finds_bytes_spelled_out() {
+        let texts = span_texts("read 4096 bytes", &make_finder());
+        assert_eq!(texts, ["4096", "bytes"]);
+    }
+
+    #[test]
+    fn finds_byte_and_bit_rates() {
+        let texts = span_texts("copied at 12.5MB/s over 1Gbps", &make_finder());
+        assert_eq!(texts, ["12", ".", "5", "MB/s", "1", "Gbps"]);
+    }
+
+    #[test]
+    fn finds_request_rates() {
+        let texts = span_texts("serving 300 req/s and 1200 ops/sec", &make_finder());
+        assert_eq!(texts, ["300", "req/s", "1200", "ops/sec"]);
+    }
+
+    #[test]
+    fn finds_percentages() {
+        let texts = span_texts("cpu 99.9% disk 42 %", &make_finder());
+        assert_eq!(texts, ["99", ".", "9", "%", "42", "%"]);
+    }
+
+    #[test]
+    fn durations_and_plain_numbers_do_not_match() {
+        assert!(span_texts("took 150ms, waited 5m, status 200", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn unit_must_terminate_the_word() {
+        assert!(span_texts("5Bytes 3MBx 2 Bob", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn lowercase_byte_unit_is_not_a_size() {
+        assert!(span_texts("2 b 4mb", &make_finder()).is_empty());
+    }
+}
@@ -0,0 +1,441 @@
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+
+use crate::core::config::SqlConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Reserved words styled as keywords, matched case-insensitively.
+const KEYWORDS: &[&str] = &[
+    "SELECT",
+    "INSERT",
+    "UPDATE",
+    "DELETE",
+    "WITH",
+    "CREATE",
+    "ALTER",
+    "DROP",
+    "TRUNCATE",
+    "MERGE",
+    "FROM",
+    "WHERE",
+    "INTO",
+    "VALUES",
+    "SET",
+    "JOIN",
+    "INNER",
+    "LEFT",
+    "RIGHT",
+    "FULL",
+    "OUTER",
+    "CROSS",
+    "ON",
+    "USING",
+    "AND",
+    "OR",
+    "NOT",
+    "NULL",
+    "IS",
+    "IN",
+    "EXISTS",
+    "BETWEEN",
+    "LIKE",
+    "ILIKE",
+    "AS",
+    "DISTINCT",
+    "ALL",
+    "ANY",
+    "GROUP",
+    "BY",
+    "ORDER",
+    "HAVING",
+    "LIMIT",
+    "OFFSET",
+    "FETCH",
+    "NEXT",
+    "ROWS",
+    "ONLY",
+    "ASC",
+    "DESC",
+    "UNION",
+    "INTERSECT",
+    "EXCEPT",
+    "CASE",
+    "WHEN",
+    "THEN",
+    "ELSE",
+    "END",
+    "RETURNING",
+    "CONFLICT",
+    "DO",
+    "NOTHING",
+    "TABLE",
+    "INDEX",
+    "VIEW",
+    "IF",
+    "PRIMARY",
+    "KEY",
+    "FOREIGN",
+    "REFERENCES",
+    "DEFAULT",
+    "UNIQUE",
+    "CONSTRAINT",
+    "ADD",
+    "COLUMN",
+    "TRUE",
+    "FALSE",
+    "FOR",
+    "SHARE",
+    "NOWAIT",
+    "SKIP",
+    "LOCKED",
+];
+
+/// The clause keywords that must follow a statement's first keyword before
+/// the line counts as SQL; `select a file` alone is prose.
+fn companions(opener: &str) -> &'static [&'static str] {
+    match opener {
+        "SELECT" | "DELETE" => &["FROM"],
+        "INSERT" | "MERGE" => &["INTO"],
+        "UPDATE" => &["SET"],
+        "WITH" => &["SELECT"],
+        "CREATE" | "ALTER" | "DROP" | "TRUNCATE" => &["TABLE", "INDEX", "VIEW"],
+        _ => &[],
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SqlFinder {
+    ac: AhoCorasick,
+    keyword: StyleId,
+    identifier: StyleId,
+    parameter: StyleId,
+    string: StyleId,
+    number: StyleId,
+}
+
+impl SqlFinder {
+    pub fn new(config: SqlConfig, palette: &mut Palette) -> Self {
+        let ac = AhoCorasickBuilder::new()
+            .ascii_case_insensitive(true)
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(KEYWORDS)
+            .expect("hardcoded SQL keywords must compile");
+
+        Self {
+            ac,
+            keyword: palette.intern(config.keyword),
+            identifier: palette.intern(config.identifier),
+            parameter: palette.intern(config.parameter),
+            string: palette.intern(config.string),
+            number: palette.intern(config.number),
+        }
+    }
+
+    /// Styles the statement in `bytes[start..end]`; `keywords` holds the span
+    /// and canonical spelling of every keyword in the line, sorted by start.
+    fn push_statement(
+        &self,
+        bytes: &[u8],
+        start: usize,
+        end: usize,
+        keywords: &[(usize, usize, &str)],
+        collector: &mut Collector,
+    ) {
+        let mut i = start;
+        while i < end {
+            let b = bytes[i];
+            let prev_is_word = i > 0 && is_word_byte(bytes[i - 1]);
+
+            match b {
+                b'\'' => {
+                    let close = string_end(bytes, i, end);
+                    collector.push(i, close, self.string);
+                    i = close;
+                }
+                b'"' | b'`' => {
+                    let close = bytes[i + 1..end]
+                        .iter()
+                        .position(|&c| c == b)
+                        .map_or(end, |p| i + p + 2);
+                    collector.push(i, close, self.identifier);
+                    i = close;
+                }
+                b'?' => {
+                    collector.push(i, i + 1, self.parameter);
+                    i += 1;
+                }
+                b'$' if bytes.get(i + 1).is_some_and(u8::is_ascii_digit) => {
+                    let len = 1 + run(&bytes[i + 1..end], |c| c.is_ascii_digit());
+                    collector.push(i, i + len, self.parameter);
+                    i += len;
+                }
+                // `::int` is a Postgres cast, not a parameter.
+                b':' if bytes.get(i + 1) == Some(&b':') => i += 2,
+                b':' if !prev_is_word && bytes.get(i + 1).is_some_and(|&c| is_word_start(c)) => {
+                    let len = 1 + run(&bytes[i + 1..end], is_word_byte);
+                    collector.push(i, i + len, self.parameter);
+                    i += len;
+                }
+                b'0'..=b'9' if !prev_is_word => {
+                    let len = run(&bytes[i..end], |c| c.is_ascii_digit() || c == b'.');
+                    if bytes.get(i + len).is_some_and(|&c| is_word_byte(c)) {
+                        i += len;
+                        continue;
+                    }
+                    collector.push(i, i + len, self.number);
+                    i += len;
+                }
+                _ if is_word_start(b) && !prev_is_word => {
+                    let len = run(&bytes[i..end], is_word_byte);
+                    let is_keyword = keywords
+                        .binary_search_by_key(&i, |&(s, _, _)| s)
+                        .is_ok_and(|k| keywords[k].1 == i + len);
+                    let style = if is_keyword { self.keyword } else { self.identifier };
+                    collector.push(i, i + len, style);
+                    i += len;
+                }
+                _ => i += 1,
+            }
+        }
+    }
+}
+
+fn is_word_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_'
+}
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+fn run(bytes: &[u8], pred: impl Fn(u8) -> bool) -> usize {
+    bytes.iter().take_while(|&&c| pred(c)).count()
+}
+
+/// The end of the `'…'` literal opened at `open`, past its closing quote;
+/// `''` is an escaped quote. Unterminated literals run to `end`.
+fn string_end(bytes: &[u8], open: usize, end: usize) -> usize {
+    let mut i = open + 1;
+    while i < end {
+        if bytes[i] == b'\'' {
+            if bytes.get(i + 1) == Some(&b'\'') && i + 1 < end {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+    end
+}
+
+/// Whether a statement may start at `start`: at the start of the line (past
+/// any quote), after a `key:`/`key=` or a `mysql>`/`psql=#` prompt, or after
+/// a `SQL`/`query`/`statement` marker. Mid-sentence keywords are prose.
+fn at_statement_position(bytes: &[u8], start: usize) -> bool {
+    let before = &bytes[..start];
+    let Some(last) = before
+        .iter()
+        .rposition(|&b| !matches!(b, b' ' | b'\t' | b'"' | b'\'' | b'`'))
+    else {
+        return true;
+    };
+    if matches!(before[last], b':' | b'=' | b'>' | b'#') {
+        return true;
+    }
+
+    let word_len = before[..=last].iter().rev().take_while(|&&b| is_word_byte(b)).count();
+    let word = &before[last + 1 - word_len..=last];
+    ["sql", "query", "statement"]
+        .iter()
+        .any(|marker| word.eq_ignore_ascii_case(marker.as_bytes()))
+}
+
+/// Whether the statement in `bytes[start..end]`, whose opener is
+/// `opener` and whose companion clause starts at `companion`, reads as SQL
+/// rather than prose: opener and clause written in capitals, or a token that
+/// prose does not use (`*`, a comparison, a parameter, a literal, a quoted
+/// identifier, parentheses, or a comma in the select list).
+fn has_sql_evidence(bytes: &[u8], start: usize, end: usize, opener: (usize, usize), companion: (usize, usize)) -> bool {
+    let upper = |(s, e): (usize, usize)| bytes[s..e].iter().all(u8::is_ascii_uppercase);
+    if upper(opener) && upper(companion) {
+        return true;
+    }
+
+    let select_list = &bytes[opener.1..companion.0];
+    if select_list.contains(&b',') {
+        return true;
+    }
+
+    (start..end).any(|i| match bytes[i] {
+        b'*' | b'=' | b'<' | b'>' | b'?' | b'\'' | b'"' | b'`' | b'(' => true,
+        b'$' => bytes.get(i + 1).is_some_and(u8::is_ascii_digit),
+        b':' => (i == 0 || !is_word_byte(bytes[i - 1])) && bytes.get(i + 1).is_some_and(|&c| is_word_start(c)),
+        _ => false,
+    })
+}
+
+/// The end of the statement starting at `start`: just past the first `;`
+/// outside a string literal, or the end of the line.
+fn statement_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' => i = string_end(bytes, i, bytes.len()),
+            b';' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+impl Finder for SqlFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+
+        let keywords: Vec<(usize, usize, &str)> = self
+            .ac
+            .find_iter(bytes)
+            .filter(|m| {
+                let left_ok = m.start() == 0 || !is_word_byte(bytes[m.start() - 1]);
+                let right_ok = m.end() == bytes.len() || !is_word_byte(bytes[m.end()]);
+                left_ok && right_ok
+            })
+            .map(|m| (m.start(), m.end(), KEYWORDS[m.pattern().as_usize()]))
+            .collect();
+
+        let mut resume = 0;
+        for (k, &(start, opener_end, opener)) in keywords.iter().enumerate() {
+            if start < resume || companions(opener).is_empty() || !at_statement_position(bytes, start) {
+                continue;
+            }
+
+            let end = statement_end(bytes, start);
+            let companion = keywords[k + 1..]
+                .iter()
+                .take_while(|&&(s, _, _)| s < end)
+                .find(|(_, _, word)| companions(opener).contains(word));
+            let Some(&(companion_start, companion_end, _)) = companion else {
+                continue;
+            };
+            if !has_sql_evidence(bytes, start, end, (start, opener_end), (companion_start, companion_end)) {
+                continue;
+            }
+
+            self.push_statement(bytes, start, end, &keywords, collector);
+            resume = end;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> SqlFinder {
+        SqlFinder::new(
+            SqlConfig {
+                keyword: Style::new().fg(Color::Blue),
+                identifier: Style::new().fg(Color::Magenta),
+                parameter: Style::new().fg(Color::Red),
+                string: Style::new().fg(Color::Yellow),
+                number: Style::new().fg(Color::Cyan),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    fn styled(input: &str) -> Vec<(&str, StyleId)> {
+        let mut collector = Collector::new();
+        make_finder().find_spans(input, &mut collector);
+        collector
+            .into_spans()
+            .iter()
+            .map(|s| (&input[s.start..s.end], s.style))
+            .collect()
+    }
+
+    #[test]
+    fn select_with_bind_parameter() {
+        let texts = span_texts("SELECT u.id FROM users u WHERE u.email = $1", &make_finder());
+        assert_eq!(
+            texts,
+            ["SELECT", "u", "id", "FROM", "users", "u", "WHERE", "u", "email", "$1"]
+        );
+    }
+
+    #[test]
+    fn token_kinds_get_their_own_styles() {
+        let finder = make_finder();
+        let spans = styled("Hibernate: select * from orders where status = 'it''s' and total > 10.5 limit ?");
+        let style_of = |text: &str| spans.iter().find(|(t, _)| *t == text).unwrap().1;
+
+        assert_eq!(style_of("select"), finder.keyword);
+        assert_eq!(style_of("orders"), finder.identifier);
+        assert_eq!(style_of("'it''s'"), finder.string);
+        assert_eq!(style_of("10.5"), finder.number);
+        assert_eq!(style_of("?"), finder.parameter);
+        assert!(!spans.iter().any(|(t, _)| *t == "Hibernate"));
+    }
+
+    #[test]
+    fn named_parameters_and_casts() {
+        let texts = span_texts("UPDATE jobs SET state = :state WHERE id = :id::bigint", &make_finder());
+        assert!(texts.contains(&":state"));
+        assert!(texts.contains(&":id"));
+        assert!(!texts.contains(&":bigint"));
+        assert!(texts.contains(&"bigint"));
+    }
+
+    #[test]
+    fn insert_with_quoted_identifiers() {
+        let texts = span_texts(r#"INSERT INTO "users" (`name`) VALUES ('ann')"#, &make_finder());
+        assert_eq!(texts, ["INSERT", "INTO", r#""users""#, "`name`", "VALUES", "'ann'"]);
+    }
+
+    #[test]
+    fn statement_ends_at_semicolon() {
+        let texts = span_texts("DELETE FROM t; done in 3 steps", &make_finder());
+        assert_eq!(texts, ["DELETE", "FROM", "t"]);
+    }
+
+    #[test]
+    fn keywords_inside_identifiers_are_not_keywords() {
+        let finder = make_finder();
+        let spans = styled("SELECT order_id, inserted_at FROM orders");
+        assert!(spans.contains(&("order_id", finder.identifier)));
+        assert!(spans.contains(&("inserted_at", finder.identifier)));
+    }
+
+    #[test]
+    fn opener_without_its_clause_is_prose() {
+        assert!(span_texts("please select an option", &make_finder()).is_empty());
+        assert!(span_texts("update available: v2", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn prose_with_a_clause_is_not_sql() {
+        let finder = make_finder();
+        for input in [
+            "Please select an item from the list where you like",
+            "update: delete the file from disk",
+            "we select from a few options",
+        ] {
+            assert!(span_texts(input, &finder).is_empty(), "{input}");
+        }
+    }
+
+    #[test]
+    fn statements_after_markers() {
+        let finder = make_finder();
+        assert_eq!(
+            span_texts("executing query select id, name from users", &finder)[0],
+            "select"
+        );
+        assert_eq!(span_texts(r#"{"sql":"DELETE FROM t"}"#, &finder)[0], "DELETE");
+        assert_eq!(span_texts("mysql> SELECT 1 FROM dual;", &finder)[0], "SELECT");
+    }
+}
@@ -0,0 +1,308 @@
+use super::build_regex;
+use memchr::memchr2;
+use regex::{Captures, Regex};
+
+use crate::core::config::SyslogConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+#[derive(Debug, Clone)]
+pub(crate) struct SyslogFinder {
+    rfc3164_regex: Regex,
+    rfc5424_regex: Regex,
+    priority: StyleId,
+    version: StyleId,
+    timestamp: StyleId,
+    host: StyleId,
+    app: StyleId,
+    pid: StyleId,
+    bracket: StyleId,
+    msgid: StyleId,
+    sd_id: StyleId,
+    sd_key: StyleId,
+    sd_value: StyleId,
+    separator: StyleId,
+}
+
+impl SyslogFinder {
+    pub fn new(config: SyslogConfig, palette: &mut Palette) -> Self {
+        // BSD syslog as written to /var/log/syslog and by `journalctl`: the
+        // <PRI> is usually stripped, the timestamp is month-name or (rsyslog's
+        // high-precision format) RFC 3339. An RFC 3339 timestamp followed by
+        // two words is also how most application loggers start a line
+        // (`... INFO server: started`), so that form needs its <PRI>.
+        let rfc3164_pattern = r"(?xm)
+            ^
+            (?:
+                (?P<pri><\d{1,3}>)?
+                (?P<timestamp>(?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)\x20[\x20\d]\d\x20\d{2}:\d{2}:\d{2})
+              | (?P<rfc3339_pri><\d{1,3}>)
+                (?P<rfc3339_timestamp>\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(?:\.\d{1,9})?(?:Z|[+-]\d{2}:\d{2}))
+            )
+            \x20
+            (?P<host>[A-Za-z0-9][A-Za-z0-9._-]*)
+            \x20
+            (?P<app>[A-Za-z0-9_.()/@+-]+)
+            (?:(?P<open>\[)(?P<pid>\d+)(?P<close>\]))?
+            (?P<colon>:)
+        ";
+        let rfc3164_regex = build_regex(rfc3164_pattern);
+
+        // Header fields are printable US-ASCII; `-` is the nil value. SD-IDs
+        // and parameter names are printable US-ASCII minus `=`, `]` and `"`;
+        // values tolerate `\]` and `\"` escapes.
+        let rfc5424_pattern = r#"(?xm)
+            ^
+            (?P<pri><\d{1,3}>)
+            (?P<version>[1-9]\d{0,2})
+            \x20
+            (?P<timestamp>-|\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(?:\.\d{1,6})?(?:Z|[+-]\d{2}:\d{2}))
+            \x20(?P<host>[!-~]{1,255})
+            \x20(?P<app>[!-~]{1,48})
+            \x20(?P<procid>[!-~]{1,128})
+            \x20(?P<msgid>[!-~]{1,32})
+            (?:\x20(?P<sd>-|(?:\[[\x21\x23-\x3C\x3E-\x5C\x5E-\x7E]+(?:\x20[\x21\x23-\x3C\x3E-\x5C\x5E-\x7E]+="(?u:[^"\\]|\\.)*")*\])+))?
+            (?:\x20|$)
+        "#;
+        let rfc5424_regex = build_regex(rfc5424_pattern);
+
+        Self {
+            rfc3164_regex,
+            rfc5424_regex,
+            priority: palette.intern(config.priority),
+            version: palette.intern(config.version),
+            timestamp: palette.intern(config.timestamp),
+            host: palette.intern(config.host),
+            app: palette.intern(config.app),
+            pid: palette.intern(config.pid),
+            bracket: palette.intern(config.bracket),
+            msgid: palette.intern(config.msgid),
+            sd_id: palette.intern(config.sd_id),
+            sd_key: palette.intern(config.sd_key),
+            sd_value: palette.intern(config.sd_value),
+            separator: palette.intern(config.separator),
+        }
+    }
+
+    /// Pushes `field` with `style`, or with the separator style if it is the
+    /// RFC 5424 nil value `-`.
+    fn push_field(&self, caps: &Captures, name: &str, style: StyleId, collector: &mut Collector) {
+        if let Some(field) = caps.name(name) {
+            let style = if field.as_str() == "-" { self.separator } else { style };
+            collector.push(field.start(), field.end(), style);
+        }
+    }
+
+    /// Styles a validated structured-data section: `[id key="value" ...]...`.
+    fn push_structured_data(&self, sd: &str, offset: usize, collector: &mut Collector) {
+        let bytes = sd.as_bytes();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b'[' => {
+                    collector.push(offset + i, offset + i + 1, self.bracket);
+                    i += 1;
+                    let start = i;
+                    while !matches!(bytes[i], b' ' | b']') {
+                        i += 1;
+                    }
+                    collector.push(offset + start, offset + i, self.sd_id);
+                }
+                b']' => {
+                    collector.push(offset + i, offset + i + 1, self.bracket);
+                    i += 1;
+                }
+                b' ' => {
+                    // Parameter: key="value"
+                    let start = i + 1;
+                    let eq = start + bytes[start..].iter().position(|&b| b == b'=').unwrap();
+                    collector.push(offset + start, offset + eq, self.sd_key);
+                    collector.push(offset + eq, offset + eq + 1, self.separator);
+
+                    let mut j = eq + 2;
+                    while bytes[j] != b'"' {
+                        j += if bytes[j] == b'\\' { 2 } else { 1 };
+                    }
+                    collector.push(offset + eq + 1, offset + j + 1, self.sd_value);
+                    i = j + 1;
+                }
+                _ => i += 1,
+            }
+        }
+    }
+}
+
+impl Finder for SyslogFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if memchr2(b':', b'<', input.as_bytes()).is_none() {
+            return;
+        }
+
+        for caps in self.rfc5424_regex.captures_iter(input) {
+            let pri = caps.name("pri").unwrap();
+            collector.push(pri.start(), pri.end(), self.priority);
+            let version = caps.name("version").unwrap();
+            collector.push(version.start(), version.end(), self.version);
+
+            self.push_field(&caps, "timestamp", self.timestamp, collector);
+            self.push_field(&caps, "host", self.host, collector);
+            self.push_field(&caps, "app", self.app, collector);
+            self.push_field(&caps, "procid", self.pid, collector);
+            self.push_field(&caps, "msgid", self.msgid, collector);
+
+            if let Some(sd) = caps.name("sd") {
+                if sd.as_str() == "-" {
+                    collector.push(sd.start(), sd.end(), self.separator);
+                } else {
+                    self.push_structured_data(sd.as_str(), sd.start(), collector);
+                }
+            }
+        }
+
+        for caps in self.rfc3164_regex.captures_iter(input) {
+            if let Some(pri) = caps.name("pri").or_else(|| caps.name("rfc3339_pri")) {
+                collector.push(pri.start(), pri.end(), self.priority);
+            }
+
+            self.push_field(&caps, "timestamp", self.timestamp, collector);
+            self.push_field(&caps, "rfc3339_timestamp", self.timestamp, collector);
+            self.push_field(&caps, "host", self.host, collector);
+            self.push_field(&caps, "app", self.app, collector);
+
+            if let (Some(open), Some(pid), Some(close)) = (caps.name("open"), caps.name("pid"), caps.name("close")) {
+                collector.push(open.start(), open.end(), self.bracket);
+                collector.push(pid.start(), pid.end(), self.pid);
+                collector.push(close.start(), close.end(), self.bracket);
+            }
+
+            let colon = caps.name("colon").unwrap();
+            collector.push(colon.start(), colon.end(), self.separator);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> SyslogFinder {
+        SyslogFinder::new(
+            SyslogConfig {
+                priority: Style::new().fg(Color::BrightBlack),
+                version: Style::new().fg(Color::BrightBlack).bold(),
+                timestamp: Style::new().fg(Color::Blue),
+                host: Style::new().fg(Color::Magenta),
+                app: Style::new().fg(Color::Yellow),
+                pid: Style::new().fg(Color::Cyan),
+                bracket: Style::new().fg(Color::Red),
+                msgid: Style::new().fg(Color::Green),
+                sd_id: Style::new().fg(Color::Green).italic(),
+                sd_key: Style::new().faint(),
+                sd_value: Style::new().fg(Color::BrightYellow),
+                separator: Style::new().fg(Color::White),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn bsd_header_with_pid() {
+        let texts = span_texts("Oct 17 12:00:01 bastion sshd[4242]: Accepted publickey", &make_finder());
+        assert_eq!(texts, ["Oct 17 12:00:01", "bastion", "sshd", "[", "4242", "]", ":"]);
+    }
+
+    #[test]
+    fn bsd_header_without_pid_and_padded_day() {
+        let texts = span_texts("Oct  7 12:00:01 host kernel: usb 1-1: new device", &make_finder());
+        assert_eq!(texts, ["Oct  7 12:00:01", "host", "kernel", ":"]);
+    }
+
+    #[test]
+    fn bsd_header_with_priority_and_rfc3339_timestamp() {
+        let texts = span_texts(
+            "<34>2026-10-17T12:00:01.123456+02:00 web-1 postfix/smtpd[1894]: connect",
+            &make_finder(),
+        );
+        assert_eq!(
+            texts,
+            [
+                "<34>",
+                "2026-10-17T12:00:01.123456+02:00",
+                "web-1",
+                "postfix/smtpd",
+                "[",
+                "1894",
+                "]",
+                ":"
+            ]
+        );
+    }
+
+    #[test]
+    fn rfc5424_header_with_structured_data() {
+        let input = r#"<165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog - ID47 [exampleSDID@32473 iut="3" eventSource="Application"] An application event"#;
+        let texts = span_texts(input, &make_finder());
+        assert_eq!(
+            texts,
+            [
+                "<165>",
+                "1",
+                "2003-10-11T22:14:15.003Z",
+                "mymachine.example.com",
+                "evntslog",
+                "-",
+                "ID47",
+                "[",
+                "exampleSDID@32473",
+                "iut",
+                "=",
+                "\"3\"",
+                "eventSource",
+                "=",
+                "\"Application\"",
+                "]"
+            ]
+        );
+    }
+
+    #[test]
+    fn rfc5424_structured_data_with_escapes_and_multiple_elements() {
+        let input = r#"<34>1 - host app 99 - [a@1 x="say \"hi\" ]"][b@2] msg"#;
+        let texts = span_texts(input, &make_finder());
+        assert!(texts.contains(&r#""say \"hi\" ]""#));
+        assert!(texts.contains(&"a@1"));
+        assert!(texts.contains(&"b@2"));
+        assert!(!texts.contains(&"msg"));
+    }
+
+    #[test]
+    fn rfc5424_nil_structured_data() {
+        let texts = span_texts("<34>1 2026-10-17T12:00:01Z host app 1 ID1 - msg", &make_finder());
+        assert_eq!(texts.last(), Some(&"-"));
+    }
+
+    #[test]
+    fn header_must_start_the_line() {
+        assert!(span_texts("seen at Oct 17 12:00:01 host sshd[1]: x", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn plain_time_is_not_a_header() {
+        assert!(span_texts("12:00:01 host sshd[1]: x", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn rfc3339_timestamp_needs_a_priority() {
+        for input in [
+            "2026-10-17T12:00:00Z INFO server: started",
+            "2026-10-17T12:00:00Z ERROR db: timeout",
+            "2026-10-17T12:00:01.123456+02:00 web-1 postfix/smtpd[1894]: connect",
+        ] {
+            assert!(span_texts(input, &make_finder()).is_empty(), "{input}");
+        }
+    }
+}
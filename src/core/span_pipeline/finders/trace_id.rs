@@ -0,0 +1,217 @@
+use super::build_regex;
+use regex::Regex;
+
+use crate::core::config::TraceIdConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+#[derive(Debug, Clone)]
+pub(crate) struct TraceIdFinder {
+    regex: Regex,
+    version: StyleId,
+    trace_id: StyleId,
+    ids: Vec<StyleId>,
+    span_id: StyleId,
+    flags: StyleId,
+    separator: StyleId,
+}
+
+impl TraceIdFinder {
+    pub fn new(config: TraceIdConfig, palette: &mut Palette) -> Self {
+        // A W3C `traceparent` value is distinctive enough to stand alone.
+        // Bare trace and span ids are just hex, so they need their key:
+        // `trace_id=`, `"traceId": "`, `X-B3-SpanId: `. Only the value is
+        // styled; the key is left to the key-value highlighter.
+        let pattern = r#"(?x)
+            (?P<version>[0-9a-f]{2})(?P<d1>-)(?P<tp_trace>[0-9a-f]{32})(?P<d2>-)(?P<tp_span>[0-9a-f]{16})(?P<d3>-)(?P<flags>[0-9a-f]{2})
+          | (?i:(?:x-b3-)?trace[_.-]?id) "?\x20*[=:]\x20*"? (?P<trace>[0-9a-fA-F]{32}|[0-9a-fA-F]{16})
+          | (?i:(?:x-b3-)?(?:parent[_-]?)?span[_.-]?id) "?\x20*[=:]\x20*"? (?P<span>[0-9a-fA-F]{16})
+          | (?i:(?:x-)?(?:request|correlation)[_.-]?id) "?\x20*[=:]\x20*"? (?P<request>[A-Za-z0-9](?:[\w.:-]*[A-Za-z0-9])?)
+        "#;
+
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            version: palette.intern(config.version),
+            trace_id: palette.intern(config.trace_id),
+            ids: config.ids.into_iter().map(|style| palette.intern(style)).collect(),
+            span_id: palette.intern(config.span_id),
+            flags: palette.intern(config.flags),
+            separator: palette.intern(config.separator),
+        }
+    }
+
+    /// The style of a trace or request id: picked from `ids` by a hash of the
+    /// id, so every line of one trace gets the same style, or `trace_id` when
+    /// `ids` is empty.
+    fn id_style(&self, id: &str) -> StyleId {
+        match self.ids.len() {
+            0 => self.trace_id,
+            n => self.ids[usize::try_from(fnv1a(id) % n as u64).unwrap()],
+        }
+    }
+}
+
+/// FNV-1a over the lowercased id. Unlike the standard library's hasher it is
+/// stable across runs, and case does not split `4BF9…` from `4bf9…`.
+fn fnv1a(id: &str) -> u64 {
+    id.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ u64::from(b.to_ascii_lowercase())).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+impl Finder for TraceIdFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+        for caps in self.regex.captures_iter(input) {
+            let whole = caps.get(0).unwrap();
+            // Part of a longer key, hex run or word: `subrequest_id=`,
+            // a 33-digit hex string.
+            let glued = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+            let glued_before = whole.start() > 0 && glued(bytes[whole.start() - 1]);
+            let glued_after = bytes.get(whole.end()).is_some_and(|&b| glued(b) || b == b'-');
+            if glued_before || glued_after {
+                continue;
+            }
+
+            if let Some(trace) = caps.name("tp_trace") {
+                let span = caps.name("tp_span").unwrap();
+                let segments = [
+                    (caps.name("version").unwrap(), self.version),
+                    (caps.name("d1").unwrap(), self.separator),
+                    (trace, self.id_style(trace.as_str())),
+                    (caps.name("d2").unwrap(), self.separator),
+                    (span, self.span_id),
+                    (caps.name("d3").unwrap(), self.separator),
+                    (caps.name("flags").unwrap(), self.flags),
+                ];
+                for (m, style) in segments {
+                    collector.push(m.start(), m.end(), style);
+                }
+            } else if let Some(id) = caps.name("trace").or_else(|| caps.name("request")) {
+                collector.push(id.start(), id.end(), self.id_style(id.as_str()));
+            } else if let Some(span) = caps.name("span") {
+                collector.push(span.start(), span.end(), self.span_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    const TRACE: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+
+    fn make_finder() -> TraceIdFinder {
+        TraceIdFinder::new(
+            TraceIdConfig {
+                version: Style::new().faint(),
+                trace_id: Style::new().fg(Color::Blue),
+                ids: vec![
+                    Style::new().fg(Color::Green),
+                    Style::new().fg(Color::Yellow),
+                    Style::new().fg(Color::Magenta),
+                ],
+                span_id: Style::new().fg(Color::Cyan),
+                flags: Style::new().fg(Color::Red),
+                separator: Style::new().fg(Color::White),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    fn styled(input: &str) -> Vec<(&str, StyleId)> {
+        let mut collector = Collector::new();
+        make_finder().find_spans(input, &mut collector);
+        collector
+            .into_spans()
+            .iter()
+            .map(|s| (&input[s.start..s.end], s.style))
+            .collect()
+    }
+
+    #[test]
+    fn traceparent_segments() {
+        let finder = make_finder();
+        let input = format!("traceparent: 00-{TRACE}-00f067aa0ba902b7-01");
+        assert_eq!(
+            styled(&input),
+            [
+                ("00", finder.version),
+                ("-", finder.separator),
+                (TRACE, finder.id_style(TRACE)),
+                ("-", finder.separator),
+                ("00f067aa0ba902b7", finder.span_id),
+                ("-", finder.separator),
+                ("01", finder.flags),
+            ]
+        );
+    }
+
+    #[test]
+    fn keyed_ids() {
+        let finder = make_finder();
+        let input = format!("trace_id={TRACE} span_id=00f067aa0ba902b7");
+        assert_eq!(span_texts(&input, &finder), [TRACE, "00f067aa0ba902b7"]);
+        let input = format!(r#"{{"traceId":"{TRACE}","parentSpanId":"00f067aa0ba902b7"}}"#);
+        assert_eq!(span_texts(&input, &finder), [TRACE, "00f067aa0ba902b7"]);
+        assert_eq!(
+            span_texts("X-B3-TraceId: 463ac35c9f6413ad", &finder),
+            ["463ac35c9f6413ad"]
+        );
+    }
+
+    #[test]
+    fn request_ids() {
+        let finder = make_finder();
+        assert_eq!(
+            span_texts("X-Request-Id: req_8f14e45f-ceea ok", &finder),
+            ["req_8f14e45f-ceea"]
+        );
+        assert_eq!(span_texts("correlation_id=abc.123 done", &finder), ["abc.123"]);
+    }
+
+    #[test]
+    fn the_same_trace_keeps_its_style() {
+        let finder = make_finder();
+        let header = styled(&format!("00-{TRACE}-00f067aa0ba902b7-01"))[2].1;
+        let field = styled(&format!("trace_id={}", TRACE.to_ascii_uppercase()))[0].1;
+        assert_eq!(header, field);
+
+        // Distinct traces spread over the styles.
+        let first = finder.id_style(&format!("{:032x}", 0));
+        assert!((1..16).any(|i| finder.id_style(&format!("{i:032x}")) != first));
+    }
+
+    #[test]
+    fn empty_ids_fall_back_to_trace_id() {
+        let finder = TraceIdFinder::new(
+            TraceIdConfig {
+                ids: Vec::new(),
+                ..TraceIdConfig::default()
+            },
+            &mut Palette::new(),
+        );
+        assert_eq!(finder.id_style(TRACE), finder.trace_id);
+    }
+
+    #[test]
+    fn non_trace_ids_do_not_match() {
+        let finder = make_finder();
+        for input in [
+            &format!("trace_id={TRACE}0") as &str,
+            "trace_id=4bf92f35",
+            "span_id=xyz",
+            "subrequest_id=abc",
+            "ff-4bf92f3577b34da6-00f067aa0ba902b7-01",
+            "release-2026-10-17",
+        ] {
+            assert!(span_texts(input, &finder).is_empty(), "{input}");
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use super::build_regex;
+use memchr::memchr2;
+use regex::Regex;
+
+use crate::core::config::WindowsPathConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+#[derive(Debug, Clone)]
+pub(crate) struct WindowsPathFinder {
+    regex: Regex,
+    drive: StyleId,
+    segment: StyleId,
+    separator: StyleId,
+}
+
+impl WindowsPathFinder {
+    pub fn new(config: WindowsPathConfig, palette: &mut Palette) -> Self {
+        // A root — drive letter, UNC `\\server\share` or `%VAR%` — then
+        // segments; a UNC share needs no trailing separator. Quoted paths may
+        // contain spaces; bare ones stop at spaces and at punctuation that
+        // usually ends a path in prose.
+        let pattern = r#"(?x)
+            "(?P<quoted>
+                (?:(?:\b[A-Za-z]:|%[A-Za-z_][A-Za-z0-9_]*%)[\\/]|\\\\[A-Za-z0-9._$-]+\\[A-Za-z0-9._$-]+[\\/]?)
+                (?:(?u:[^\\/:*?"<>|\n])+(?:[\\/](?u:[^\\/:*?"<>|\n])+)*[\\/]?)?
+            )"
+          | (?P<bare>
+                (?:(?:\b[A-Za-z]:|%[A-Za-z_][A-Za-z0-9_]*%)[\\/]|\\\\[A-Za-z0-9._$-]+\\[A-Za-z0-9._$-]+[\\/]?)
+                (?:(?u:[^\s\\/:*?"<>|,;'()\[\]])+(?:[\\/](?u:[^\s\\/:*?"<>|,;'()\[\]])+)*[\\/]?)?
+            )
+        "#;
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            drive: palette.intern(config.drive),
+            segment: palette.intern(config.segment),
+            separator: palette.intern(config.separator),
+        }
+    }
+
+    /// Styles the root at the start of `path` and returns its length.
+    fn push_root(&self, path: &str, offset: usize, collector: &mut Collector) -> usize {
+        let bytes = path.as_bytes();
+
+        if let Some(unc) = path.strip_prefix(r"\\") {
+            let server = unc.find('\\').unwrap();
+            let share = unc[server + 1..].find(['\\', '/']).unwrap_or(unc.len() - server - 1);
+            collector.push(offset, offset + 2, self.separator);
+            collector.push(offset + 2, offset + 2 + server, self.drive);
+            collector.push(offset + 2 + server, offset + 3 + server, self.separator);
+            collector.push(offset + 3 + server, offset + 3 + server + share, self.drive);
+            return 3 + server + share;
+        }
+
+        let len = if bytes[0] == b'%' {
+            path[1..].find('%').unwrap() + 2
+        } else {
+            2
+        };
+        collector.push(offset, offset + len, self.drive);
+        len
+    }
+}
+
+impl Finder for WindowsPathFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if memchr2(b'\\', b'/', input.as_bytes()).is_none() {
+            return;
+        }
+
+        for caps in self.regex.captures_iter(input) {
+            let path = caps.name("quoted").or_else(|| caps.name("bare")).unwrap();
+            let offset = path.start();
+            let root = self.push_root(path.as_str(), offset, collector);
+
+            let mut seg_start = None;
+            for (i, b) in path.as_str().bytes().enumerate().skip(root) {
+                if matches!(b, b'\\' | b'/') {
+                    if let Some(start) = seg_start.take() {
+                        collector.push(offset + start, offset + i, self.segment);
+                    }
+                    collector.push(offset + i, offset + i + 1, self.separator);
+                } else if seg_start.is_none() {
+                    seg_start = Some(i);
+                }
+            }
+            if let Some(start) = seg_start {
+                collector.push(offset + start, path.end(), self.segment);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> WindowsPathFinder {
+        WindowsPathFinder::new(
+            WindowsPathConfig {
+                drive: Style::new().fg(Color::Magenta),
+                segment: Style::new().fg(Color::Green),
+                separator: Style::new().fg(Color::Yellow),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn drive_path_splits_drive_segments_and_separators() {
+        let texts = span_texts(r"compiling C:\Users\build\src\main.rs", &make_finder());
+        assert_eq!(
+            texts,
+            ["C:", "\\", "Users", "\\", "build", "\\", "src", "\\", "main.rs"]
+        );
+    }
+
+    #[test]
+    fn drive_root_and_forward_slashes() {
+        assert_eq!(span_texts(r"cd D:\", &make_finder()), ["D:", "\\"]);
+        assert_eq!(
+            span_texts("at C:/tools/bin", &make_finder()),
+            ["C:", "/", "tools", "/", "bin"]
+        );
+    }
+
+    #[test]
+    fn unc_path_styles_server_and_share_as_the_root() {
+        let finder = make_finder();
+        let input = r"mounted \\fileserver\builds\nightly";
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+        let spans = collector.into_spans();
+        let texts: Vec<&str> = spans.iter().map(|s| &input[s.start..s.end]).collect();
+
+        assert_eq!(texts, ["\\\\", "fileserver", "\\", "builds", "\\", "nightly"]);
+        assert_eq!(spans[1].style, finder.drive);
+        assert_eq!(spans[3].style, finder.drive);
+        assert_eq!(spans[5].style, finder.segment);
+    }
+
+    #[test]
+    fn unc_share_without_trailing_separator() {
+        let texts = span_texts(r"mounted \\fileserver\builds at boot", &make_finder());
+        assert_eq!(texts, ["\\\\", "fileserver", "\\", "builds"]);
+    }
+
+    #[test]
+    fn environment_variable_root() {
+        let texts = span_texts(r"cache at %APPDATA%\npm-cache", &make_finder());
+        assert_eq!(texts, ["%APPDATA%", "\\", "npm-cache"]);
+    }
+
+    #[test]
+    fn quoted_path_may_contain_spaces() {
+        let texts = span_texts(r#"exec "C:\Program Files (x86)\Tool\tool.exe" --help"#, &make_finder());
+        assert_eq!(
+            texts,
+            ["C:", "\\", "Program Files (x86)", "\\", "Tool", "\\", "tool.exe"]
+        );
+    }
+
+    #[test]
+    fn bare_path_stops_at_space_and_trailing_comma() {
+        let texts = span_texts(r"wrote C:\out\a.txt, then C:\Program Files", &make_finder());
+        assert_eq!(texts, ["C:", "\\", "out", "\\", "a.txt", "C:", "\\", "Program"]);
+    }
+
+    #[test]
+    fn urls_and_bare_env_vars_do_not_match() {
+        assert!(span_texts("see https://example.com/a", &make_finder()).is_empty());
+        assert!(span_texts("%PATH% is set, ratio 3:4", &make_finder()).is_empty());
+    }
+}
@@ -0,0 +1,335 @@
+use memchr::{memchr, memchr_iter, memmem};
+
+use crate::core::config::XmlConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Failed parses allowed per line. Each one can scan to the end of the line,
+/// so this keeps lines full of stray `<` linear.
+const MAX_FAILED_ATTEMPTS: usize = 8;
+
+/// Deeper fragments are rejected rather than recursed into.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone)]
+pub(crate) struct XmlFinder {
+    bracket: StyleId,
+    tag: StyleId,
+    attribute: StyleId,
+    equals: StyleId,
+    value: StyleId,
+    comment: StyleId,
+}
+
+impl XmlFinder {
+    pub fn new(config: XmlConfig, palette: &mut Palette) -> Self {
+        Self {
+            bracket: palette.intern(config.bracket),
+            tag: palette.intern(config.tag),
+            attribute: palette.intern(config.attribute),
+            equals: palette.intern(config.equals),
+            value: palette.intern(config.value),
+            comment: palette.intern(config.comment),
+        }
+    }
+}
+
+/// Whether the `<` at `start` may open a fragment: an element, a comment,
+/// CDATA or a processing instruction. Keeps `a < b` and `</x>` out.
+fn is_candidate(bytes: &[u8], start: usize) -> bool {
+    let rest = &bytes[start + 1..];
+    rest.first().is_some_and(|&b| is_name_start(b) || b == b'?')
+        || rest.starts_with(b"!--")
+        || rest.starts_with(b"![CDATA[")
+}
+
+fn is_name_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_' || b == b':' || b >= 0x80
+}
+
+fn is_name_byte(b: u8) -> bool {
+    is_name_start(b) || b.is_ascii_digit() || b == b'-' || b == b'.'
+}
+
+/// A well-formedness check over one fragment that records the spans to push.
+/// Nothing reaches the collector unless the whole fragment parses.
+struct Parser<'a> {
+    finder: &'a XmlFinder,
+    bytes: &'a [u8],
+    pos: usize,
+    spans: Vec<(usize, usize, StyleId)>,
+}
+
+impl Parser<'_> {
+    fn push(&mut self, start: usize, end: usize, style: StyleId) {
+        self.spans.push((start, end, style));
+    }
+
+    fn eat(&mut self, token: &[u8], style: StyleId) -> Option<()> {
+        if !self.bytes[self.pos..].starts_with(token) {
+            return None;
+        }
+        self.push(self.pos, self.pos + token.len(), style);
+        self.pos += token.len();
+        Some(())
+    }
+
+    /// Skips whitespace and returns whether there was any.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.bytes.get(self.pos).is_some_and(u8::is_ascii_whitespace) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+
+    fn name(&mut self) -> Option<&[u8]> {
+        let start = self.pos;
+        if !self.bytes.get(start).copied().is_some_and(is_name_start) {
+            return None;
+        }
+        self.pos += 1;
+        while self.bytes.get(self.pos).copied().is_some_and(is_name_byte) {
+            self.pos += 1;
+        }
+        Some(&self.bytes[start..self.pos])
+    }
+
+    /// Consumes up to and including `terminator`, styling it all as `style`.
+    fn until(&mut self, start: usize, terminator: &[u8], style: StyleId) -> Option<()> {
+        let end = self.pos + memmem::find(&self.bytes[self.pos..], terminator)? + terminator.len();
+        self.push(start, end, style);
+        self.pos = end;
+        Some(())
+    }
+
+    /// A comment, CDATA section, processing instruction or element.
+    fn item(&mut self, depth: usize) -> Option<()> {
+        let start = self.pos;
+        let rest = &self.bytes[start..];
+
+        if rest.starts_with(b"<!--") {
+            self.pos += 4;
+            self.until(start, b"-->", self.finder.comment)?;
+            // `--` may not appear inside a comment.
+            return (!self.bytes[start + 4..self.pos - 3].windows(2).any(|w| w == b"--")).then_some(());
+        }
+        if rest.starts_with(b"<![CDATA[") {
+            self.pos += 9;
+            return self.until(start, b"]]>", self.finder.comment);
+        }
+        if rest.starts_with(b"<?") {
+            self.eat(b"<?", self.finder.bracket)?;
+            let name = self.pos;
+            self.name()?;
+            self.push(name, self.pos, self.finder.tag);
+            return self.attributes(b"?>").map(|_| ());
+        }
+        self.element(depth)
+    }
+
+    fn element(&mut self, depth: usize) -> Option<()> {
+        if depth == MAX_DEPTH {
+            return None;
+        }
+
+        self.eat(b"<", self.finder.bracket)?;
+        let name_start = self.pos;
+        let name = self.name()?.to_vec();
+        self.push(name_start, self.pos, self.finder.tag);
+
+        if self.attributes(b">")? {
+            return Some(());
+        }
+
+        loop {
+            // Text runs to the next markup; it stays unstyled.
+            let next = self.pos + memchr(b'<', &self.bytes[self.pos..])?;
+            self.pos = next;
+            if self.bytes[next..].starts_with(b"</") {
+                break;
+            }
+            self.item(depth + 1)?;
+        }
+
+        self.eat(b"</", self.finder.bracket)?;
+        let close_start = self.pos;
+        if self.name()? != name.as_slice() {
+            return None;
+        }
+        self.push(close_start, self.pos, self.finder.tag);
+        self.skip_whitespace();
+        self.eat(b">", self.finder.bracket)
+    }
+
+    /// Attributes up to `end` (`>` or `?>`), or `/>` for an element. Returns
+    /// whether the tag closed itself.
+    fn attributes(&mut self, end: &[u8]) -> Option<bool> {
+        loop {
+            let spaced = self.skip_whitespace();
+            if self.eat(end, self.finder.bracket).is_some() {
+                return Some(false);
+            }
+            if end == b">" && self.eat(b"/>", self.finder.bracket).is_some() {
+                return Some(true);
+            }
+            if !spaced {
+                return None;
+            }
+
+            let name = self.pos;
+            self.name()?;
+            self.push(name, self.pos, self.finder.attribute);
+            self.skip_whitespace();
+            self.eat(b"=", self.finder.equals)?;
+            self.skip_whitespace();
+
+            let open = self.pos;
+            let quote = *self.bytes.get(open).filter(|&&b| b == b'"' || b == b'\'')?;
+            let close = open + 1 + memchr(quote, &self.bytes[open + 1..])?;
+            if memchr(b'<', &self.bytes[open + 1..close]).is_some() {
+                return None;
+            }
+            self.push(open, close + 1, self.finder.value);
+            self.pos = close + 1;
+        }
+    }
+}
+
+impl Finder for XmlFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+
+        let mut resume = 0;
+        let mut failed = 0;
+        for start in memchr_iter(b'<', bytes) {
+            if start < resume || !is_candidate(bytes, start) {
+                continue;
+            }
+
+            let mut parser = Parser {
+                finder: self,
+                bytes,
+                pos: start,
+                spans: Vec::new(),
+            };
+            if parser.item(0).is_some() {
+                for (start, end, style) in parser.spans {
+                    collector.push(start, end, style);
+                }
+                resume = parser.pos;
+            } else {
+                failed += 1;
+                if failed == MAX_FAILED_ATTEMPTS {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> XmlFinder {
+        XmlFinder::new(
+            XmlConfig {
+                bracket: Style::new().faint(),
+                tag: Style::new().fg(Color::Blue),
+                attribute: Style::new().fg(Color::Magenta),
+                equals: Style::new().fg(Color::White),
+                value: Style::new().fg(Color::Yellow),
+                comment: Style::new().fg(Color::BrightBlack),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn element_with_attribute_and_child() {
+        let texts = span_texts(r#"payload <order id="1"><item>x</item></order>"#, &make_finder());
+        assert_eq!(
+            texts,
+            // Adjacent brackets share a style and merge into one span.
+            [
+                "<", "order", "id", "=", "\"1\"", "><", "item", ">", "</", "item", "></", "order", ">"
+            ]
+        );
+    }
+
+    #[test]
+    fn text_content_is_left_to_other_finders() {
+        let texts = span_texts("<count>42</count>", &make_finder());
+        assert!(!texts.contains(&"42"));
+    }
+
+    #[test]
+    fn self_closing_element_and_single_quotes() {
+        let texts = span_texts("<br class='x'/>", &make_finder());
+        assert_eq!(texts, ["<", "br", "class", "=", "'x'", "/>"]);
+    }
+
+    #[test]
+    fn declaration_comment_and_cdata() {
+        let finder = make_finder();
+        let input = r#"<?xml version="1.0"?><a><!-- note -->text<![CDATA[<raw>]]></a>"#;
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+        let spans = collector.into_spans();
+        let texts: Vec<&str> = spans.iter().map(|s| &input[s.start..s.end]).collect();
+
+        assert_eq!(&texts[..5], ["<?", "xml", "version", "=", "\"1.0\""]);
+        let comment = spans
+            .iter()
+            .find(|s| &input[s.start..s.end] == "<!-- note -->")
+            .unwrap();
+        let cdata = spans
+            .iter()
+            .find(|s| &input[s.start..s.end] == "<![CDATA[<raw>]]>")
+            .unwrap();
+        assert_eq!(comment.style, finder.comment);
+        assert_eq!(cdata.style, finder.comment);
+    }
+
+    #[test]
+    fn namespaced_soap_envelope() {
+        let texts = span_texts(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"><soap:Body/></soap:Envelope>"#,
+            &make_finder(),
+        );
+        assert!(texts.contains(&"soap:Envelope"));
+        assert!(texts.contains(&"xmlns:soap"));
+        assert!(texts.contains(&"soap:Body"));
+    }
+
+    #[test]
+    fn malformed_fragments_do_not_match() {
+        for input in [
+            "<a><b></a></b>",
+            "<order id=1></order>",
+            "<open> never closed",
+            "Map<String, List<Integer>>",
+            "if a < b and c > d",
+            "<!-- bad -- comment -->",
+        ] {
+            assert!(span_texts(input, &make_finder()).is_empty(), "{input}");
+        }
+    }
+
+    #[test]
+    fn later_fragment_after_malformed_one() {
+        let texts = span_texts("<broken> then <ok/>", &make_finder());
+        assert_eq!(texts, ["<", "ok", "/>"]);
+    }
+
+    #[test]
+    fn stray_brackets_stop_after_bounded_attempts() {
+        let mut input = "<a ".repeat(MAX_FAILED_ATTEMPTS);
+        input.push_str("<ok/>");
+        assert!(span_texts(&input, &make_finder()).is_empty());
+    }
+}
@@ -23,6 +23,10 @@ pub(crate) struct ResolvedSpan {
 /// fragments that don't match exactly get `padded = false` — this is how a
 /// fragmented badge loses its surrounding spaces.
 ///
+/// A run never extends across the start or end of an annotated span, so an
+/// annotated span that wins all of its bytes resolves to exactly its own
+/// range, even next to a same-style span from another finder.
+///
 /// Returns spans sorted by position with no gaps or overlaps.
 pub(crate) fn merge_spans(input_len: usize, spans: &[Span]) -> Vec<ResolvedSpan> {
     if spans.is_empty() {
@@ -52,6 +56,14 @@ pub(crate) fn merge_spans(input_len: usize, spans: &[Span]) -> Vec<ResolvedSpan>
     let mut padded_ranges: Vec<(usize, usize)> = spans.iter().filter(|s| s.padded).map(|s| (s.start, s.end)).collect();
     padded_ranges.sort_unstable();
 
+    // Edges of annotated spans, where a run must end. As rare as padding.
+    let mut cuts: Vec<usize> = spans
+        .iter()
+        .filter(|s| s.annotated)
+        .flat_map(|s| [s.start, s.end])
+        .collect();
+    cuts.sort_unstable();
+
     // Phase 2: Run-length encode into ResolvedSpans, setting `padded` for
     // fragments whose endpoints exactly match a padded span.
     let mut result = Vec::new();
@@ -59,7 +71,8 @@ pub(crate) fn merge_spans(input_len: usize, spans: &[Span]) -> Vec<ResolvedSpan>
     while i < input_len {
         if let Some((style, _)) = style_map[i] {
             let start = i;
-            while i < input_len && style_map[i].is_some_and(|(s, _)| s == style) {
+            i += 1;
+            while i < input_len && style_map[i].is_some_and(|(s, _)| s == style) && cuts.binary_search(&i).is_err() {
                 i += 1;
             }
             let padded = padded_ranges.binary_search(&(start, i)).is_ok();
@@ -100,6 +113,7 @@ mod tests {
             style,
             priority,
             padded: true,
+            annotated: false,
         }
     }
 
@@ -171,6 +185,21 @@ mod tests {
         assert_eq!(result, vec![resolved(0, 3, red()), resolved(3, 6, blue())]);
     }
 
+    #[test]
+    fn annotated_span_is_not_joined_with_same_style_neighbours() {
+        // Same-style spans from two finders on either side of an annotated one.
+        let annotated = Span {
+            annotated: true,
+            ..Span::new(3, 6, red(), 1)
+        };
+        let spans = [Span::new(0, 3, red(), 0), annotated, Span::new(6, 9, red(), 0)];
+        let result = merge_spans(10, &spans);
+        assert_eq!(
+            result,
+            vec![resolved(0, 3, red()), resolved(3, 6, red()), resolved(6, 9, red())]
+        );
+    }
+
     #[test]
     fn intact_padded_span_keeps_padded_flag() {
         // Single keyword-style match that survives merge as-is.
@@ -10,15 +10,17 @@ use std::cell::RefCell;
 use merge::merge_spans;
 use palette::Palette;
 use render::render;
-use span::{Collector, Finder, Span};
+use span::{Annotation, Collector, Finder, Span};
 
 /// Per-call scratch buffers reused across `apply` invocations on the same
 /// thread. Pooling avoids the per-line allocations for the spans list and the
 /// collector internals. Each span carries its own priority and padding flag,
-/// so there are no parallel side-lists to keep in sync.
+/// so there are no parallel side-lists to keep in sync; annotations name the
+/// span they follow by its range.
 struct Scratch {
     collector: Collector,
     all_spans: Vec<Span>,
+    annotations: Vec<Annotation>,
 }
 
 impl Scratch {
@@ -26,6 +28,7 @@ impl Scratch {
         Self {
             collector: Collector::new(),
             all_spans: Vec::new(),
+            annotations: Vec::new(),
         }
     }
 }
@@ -58,6 +61,7 @@ impl Pipeline {
             // empty by `drain_into` at the end of each finder's iteration, but
             // a panic mid-call could leave it dirty for the next invocation.
             s.all_spans.clear();
+            s.annotations.clear();
             s.collector.reset();
 
             for (priority, finder) in self.finders.iter().enumerate() {
@@ -71,11 +75,12 @@ impl Pipeline {
                 let priority = priority as u16;
 
                 finder.find_spans(input, &mut s.collector);
-                s.collector.drain_into(&mut s.all_spans, priority);
+                s.collector.drain_into(&mut s.all_spans, &mut s.annotations, priority);
             }
 
             let resolved = merge_spans(input.len(), &s.all_spans);
-            render(input, &resolved, &self.palette)
+            s.annotations.sort_unstable_by_key(|a| (a.start, a.end));
+            render(input, &resolved, &s.annotations, &self.palette)
         })
     }
 }
@@ -90,6 +95,7 @@ mod tests {
     use finders::number::NumberFinder;
     use finders::quote::QuoteFinder;
     use finders::regex::RegexFinder;
+    use palette::StyleId;
 
     fn kw(words: &[&str], style: Style) -> KeywordConfig {
         KeywordConfig {
@@ -384,4 +390,39 @@ mod tests {
         // The 42 is still highlighted; ANSI codes are treated as opaque text
         assert!(readable.contains("[cyan]42[reset]"));
     }
+
+    /// Annotates a fixed range, for checking that annotations survive merge.
+    #[derive(Debug, Clone)]
+    struct AnnotateRange {
+        start: usize,
+        end: usize,
+        style: StyleId,
+    }
+
+    impl Finder for AnnotateRange {
+        fn find_spans(&self, _input: &str, collector: &mut Collector) {
+            collector.push_annotated(self.start, self.end, self.style, "!".to_string(), self.style);
+        }
+    }
+
+    #[test]
+    fn annotation_survives_a_same_style_neighbour() {
+        // A number styled like the annotated span right next to it: merge
+        // must not join the two, or the annotation would lose its span.
+        let mut palette = Palette::new();
+        let number = cyan_number(&mut palette);
+        let style = palette.intern(Style::new().fg(Color::Cyan));
+        let annotate = AnnotateRange {
+            start: 2,
+            end: 6,
+            style,
+        };
+        let highlighter = Pipeline::new(vec![Box::new(number), Box::new(annotate)], palette);
+
+        let result = highlighter.apply("42 abc");
+        assert_eq!(
+            result.to_string().convert_escape_codes(),
+            "[cyan]42[reset][cyan] abc[reset][cyan]![reset]"
+        );
+    }
 }
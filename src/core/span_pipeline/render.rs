@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 use super::merge::ResolvedSpan;
 use super::palette::Palette;
+use super::span::Annotation;
 
 const RESET: &str = "\x1b[0m";
 
@@ -12,8 +13,17 @@ const RESET: &str = "\x1b[0m";
 /// match only keeps its surrounding spaces if merge preserved it whole) is
 /// enforced upstream in `merge_spans` — render just reads the flag.
 ///
+/// `annotations`, sorted by `(start, end)`, follows the same rule: one is
+/// inserted after a span, or replaces its text, only if that span's range
+/// survived merge exactly.
+///
 /// Returns `Cow::Borrowed` if no spans exist (zero allocation).
-pub(crate) fn render<'a>(input: &'a str, spans: &[ResolvedSpan], palette: &Palette) -> Cow<'a, str> {
+pub(crate) fn render<'a>(
+    input: &'a str,
+    spans: &[ResolvedSpan],
+    annotations: &[Annotation],
+    palette: &Palette,
+) -> Cow<'a, str> {
     if spans.is_empty() {
         return Cow::Borrowed(input);
     }
@@ -26,16 +36,30 @@ pub(crate) fn render<'a>(input: &'a str, spans: &[ResolvedSpan], palette: &Palet
             output.push_str(&input[pos..span.start]);
         }
 
+        let annotation = annotations
+            .binary_search_by_key(&(span.start, span.end), |a| (a.start, a.end))
+            .ok()
+            .map(|i| &annotations[i]);
+
         output.push_str(&palette[span.style]);
         if span.padded {
             output.push(' ');
         }
-        output.push_str(&input[span.start..span.end]);
+        match annotation {
+            Some(annotation) if annotation.replace => output.push_str(&annotation.text),
+            _ => output.push_str(&input[span.start..span.end]),
+        }
         if span.padded {
             output.push(' ');
         }
         output.push_str(RESET);
 
+        if let Some(annotation) = annotation.filter(|a| !a.replace) {
+            output.push_str(&palette[annotation.style]);
+            output.push_str(&annotation.text);
+            output.push_str(RESET);
+        }
+
         pos = span.end;
     }
 
@@ -91,7 +115,7 @@ mod tests {
     #[test]
     fn empty_spans_returns_borrowed() {
         let input = "hello world";
-        let result = render(input, &[], &Palette::new());
+        let result = render(input, &[], &[], &Palette::new());
         assert!(matches!(result, Cow::Borrowed(_)));
         assert_eq!(&*result, "hello world");
     }
@@ -101,7 +125,7 @@ mod tests {
         let input = "hello world";
         let mut palette = Palette::new();
         let red = red(&mut palette);
-        let result = render(input, &[span(6, 11, red)], &palette);
+        let result = render(input, &[span(6, 11, red)], &[], &palette);
         assert_eq!(result.to_string().convert_escape_codes(), "hello [red]world[reset]");
     }
 
@@ -110,7 +134,7 @@ mod tests {
         let input = "abc def ghi";
         let mut palette = Palette::new();
         let red = red(&mut palette);
-        let result = render(input, &[span(0, 3, red), span(8, 11, red)], &palette);
+        let result = render(input, &[span(0, 3, red), span(8, 11, red)], &[], &palette);
         assert_eq!(
             result.to_string().convert_escape_codes(),
             "[red]abc[reset] def [red]ghi[reset]"
@@ -123,7 +147,7 @@ mod tests {
         let mut palette = Palette::new();
         let red = red(&mut palette);
         let green = green(&mut palette);
-        let result = render(input, &[span(0, 3, red), span(3, 6, green)], &palette);
+        let result = render(input, &[span(0, 3, red), span(3, 6, green)], &[], &palette);
         assert_eq!(
             result.to_string().convert_escape_codes(),
             "[red]abc[reset][green]def[reset]"
@@ -135,7 +159,7 @@ mod tests {
         let input = "x ERROR y";
         let mut palette = Palette::new();
         let bg_red = bg_red(&mut palette);
-        let result = render(input, &[padded(2, 7, bg_red)], &palette);
+        let result = render(input, &[padded(2, 7, bg_red)], &[], &palette);
         assert_eq!(result.to_string().convert_escape_codes(), "x [bg_red] ERROR [reset] y");
     }
 
@@ -144,7 +168,7 @@ mod tests {
         let input = "ERROR rest";
         let mut palette = Palette::new();
         let bg_red = bg_red(&mut palette);
-        let result = render(input, &[padded(0, 5, bg_red)], &palette);
+        let result = render(input, &[padded(0, 5, bg_red)], &[], &palette);
         assert_eq!(result.to_string().convert_escape_codes(), "[bg_red] ERROR [reset] rest");
     }
 
@@ -153,7 +177,7 @@ mod tests {
         let input = "prefix ERROR";
         let mut palette = Palette::new();
         let bg_red = bg_red(&mut palette);
-        let result = render(input, &[padded(7, 12, bg_red)], &palette);
+        let result = render(input, &[padded(7, 12, bg_red)], &[], &palette);
         assert_eq!(
             result.to_string().convert_escape_codes(),
             "prefix [bg_red] ERROR [reset]"
@@ -165,7 +189,7 @@ mod tests {
         let input = "x ERROR y";
         let mut palette = Palette::new();
         let red = red(&mut palette);
-        let result = render(input, &[span(2, 7, red)], &palette);
+        let result = render(input, &[span(2, 7, red)], &[], &palette);
         assert_eq!(result.to_string().convert_escape_codes(), "x [red]ERROR[reset] y");
     }
 
@@ -175,13 +199,81 @@ mod tests {
         let mut palette = Palette::new();
         let bg_yellow = bg_yellow(&mut palette);
         let bg_red = bg_red(&mut palette);
-        let result = render(input, &[padded(0, 4, bg_yellow), padded(10, 15, bg_red)], &palette);
+        let result = render(input, &[padded(0, 4, bg_yellow), padded(10, 15, bg_red)], &[], &palette);
         assert_eq!(
             result.to_string().convert_escape_codes(),
             "[bg_yellow] WARN [reset] then [bg_red] ERROR [reset] end"
         );
     }
 
+    #[test]
+    fn annotation_follows_its_span() {
+        let input = "ts=1760702400 ok";
+        let mut palette = Palette::new();
+        let red = red(&mut palette);
+        let green = green(&mut palette);
+        let annotation = Annotation {
+            start: 3,
+            end: 13,
+            text: " (2025-10-17T12:00:00Z)".to_string(),
+            style: green,
+            replace: false,
+        };
+        let result = render(input, &[span(3, 13, red)], &[annotation], &palette);
+        assert_eq!(
+            result.to_string().convert_escape_codes(),
+            "ts=[red]1760702400[reset][green] (2025-10-17T12:00:00Z)[reset] ok"
+        );
+    }
+
+    #[test]
+    fn annotation_dropped_when_its_span_was_split() {
+        let input = "ts=1760702400 ok";
+        let mut palette = Palette::new();
+        let red = red(&mut palette);
+        let green = green(&mut palette);
+        let annotation = Annotation {
+            start: 3,
+            end: 13,
+            text: " (2025-10-17T12:00:00Z)".to_string(),
+            style: green,
+            replace: false,
+        };
+        let result = render(input, &[span(3, 8, red), span(8, 13, green)], &[annotation], &palette);
+        assert_eq!(
+            result.to_string().convert_escape_codes(),
+            "ts=[red]17607[reset][green]02400[reset] ok"
+        );
+    }
+
+    #[test]
+    fn replacing_annotation_stands_in_for_its_span() {
+        let input = "token=abcdefghij ok";
+        let mut palette = Palette::new();
+        let red = red(&mut palette);
+        let green = green(&mut palette);
+        let annotation = Annotation {
+            start: 9,
+            end: 16,
+            text: "…[7B]".to_string(),
+            style: green,
+            replace: true,
+        };
+        let spans = [span(6, 9, red), span(9, 16, green)];
+        let result = render(input, &spans, std::slice::from_ref(&annotation), &palette);
+        assert_eq!(
+            result.to_string().convert_escape_codes(),
+            "token=[red]abc[reset][green]…[7B][reset] ok"
+        );
+
+        let split = [span(6, 9, red), span(9, 12, green), span(12, 16, red)];
+        let result = render(input, &split, &[annotation], &palette);
+        assert_eq!(
+            result.to_string().convert_escape_codes(),
+            "token=[red]abc[reset][green]def[reset][red]ghij[reset] ok"
+        );
+    }
+
     #[test]
     fn padded_followed_by_plain_fragment() {
         // The kind of output merge produces when a higher-priority finder
@@ -191,7 +283,7 @@ mod tests {
         let mut palette = Palette::new();
         let bg_red = bg_red(&mut palette);
         let green = green(&mut palette);
-        let result = render(input, &[span(2, 5, bg_red), span(5, 7, green)], &palette);
+        let result = render(input, &[span(2, 5, bg_red), span(5, 7, green)], &[], &palette);
         assert_eq!(
             result.to_string().convert_escape_codes(),
             "x [bg_red]ERR[reset][green]OR[reset] y"
@@ -8,10 +8,15 @@ use super::palette::StyleId;
 /// `priority` is the index of the finder that produced the span; lower numbers
 /// win conflicts in merge. It is `0` on a span freshly built by a `Collector`
 /// and stamped with the real value when the pipeline drains the collector (see
-/// [`Collector::drain_into`]).
+/// [`Collector::drain_into`]). Fallback spans are stamped with `u16::MAX`, so
+/// every other finder wins over them.
 ///
 /// `padded` asks render to surround the span text with a space on each side (a
 /// "badge"), but only if merge preserves the span intact.
+///
+/// `annotated` marks a span an [`Annotation`] refers to by range. Neither the
+/// collector nor merge joins it with an adjacent same-style span, so its range
+/// survives for render to find.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct Span {
     pub start: usize,
@@ -19,6 +24,7 @@ pub(crate) struct Span {
     pub style: StyleId,
     pub priority: u16,
     pub padded: bool,
+    pub annotated: bool,
 }
 
 #[cfg(test)]
@@ -30,35 +36,115 @@ impl Span {
             style,
             priority,
             padded: false,
+            annotated: false,
         }
     }
 }
 
+/// Virtual text rendered right after the span `start..end`, such as a decoded
+/// timestamp, or in its place when `replace` is set, such as a collapsed
+/// token. Like padding, it is only shown if merge preserves that span intact,
+/// so a finder that loses part of its match loses the annotation too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Annotation {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+    pub style: StyleId,
+    pub replace: bool,
+}
+
 /// Collects spans from a single finder, coalescing adjacent same-style spans.
 #[derive(Debug)]
 pub(crate) struct Collector {
     spans: Vec<Span>,
+    fallback: Vec<Span>,
+    annotations: Vec<Annotation>,
 }
 
 impl Collector {
     pub const fn new() -> Self {
-        Self { spans: Vec::new() }
+        Self {
+            spans: Vec::new(),
+            fallback: Vec::new(),
+            annotations: Vec::new(),
+        }
     }
 
     /// Push a span. If it is contiguous with the last span and shares its style
     /// and padding, extend the last span rather than pushing a new one.
     pub fn push(&mut self, start: usize, end: usize, style: StyleId) {
-        self.push_impl(start, end, style, false);
+        self.push_impl(start, end, style, false, false);
     }
 
     /// Push a span with padding. Render will insert a space before and after
     /// the span text, inside the ANSI color (creating a "badge" effect for
     /// keywords with background colors).
     pub fn push_padded(&mut self, start: usize, end: usize, style: StyleId) {
-        self.push_impl(start, end, style, true);
+        self.push_impl(start, end, style, true, false);
+    }
+
+    /// Push a span that only styles bytes no other finder claims, whatever
+    /// their order. Used for broad regions, such as key-value values, whose
+    /// contents other finders should still be able to highlight.
+    pub fn push_fallback(&mut self, start: usize, end: usize, style: StyleId) {
+        if start >= end {
+            return;
+        }
+
+        if let Some(last) = self.fallback.last_mut()
+            && last.style == style
+            && last.end == start
+        {
+            last.end = end;
+            return;
+        }
+        self.fallback.push(Span {
+            start,
+            end,
+            style,
+            priority: 0,
+            padded: false,
+            annotated: false,
+        });
+    }
+
+    /// Push a span followed by `text` in `text_style`. The text is not part of
+    /// the input; render inserts it after the span.
+    pub fn push_annotated(&mut self, start: usize, end: usize, style: StyleId, text: String, text_style: StyleId) {
+        if start >= end {
+            return;
+        }
+
+        self.push_impl(start, end, style, false, true);
+        self.annotations.push(Annotation {
+            start,
+            end,
+            text,
+            style: text_style,
+            replace: false,
+        });
     }
 
-    fn push_impl(&mut self, start: usize, end: usize, style: StyleId, padded: bool) {
+    /// Push a span whose text render replaces with `text`, in the same style.
+    /// The input itself is untouched; if another finder claims part of the
+    /// span, render shows the original text instead.
+    pub fn push_replaced(&mut self, start: usize, end: usize, style: StyleId, text: String) {
+        if start >= end {
+            return;
+        }
+
+        self.push_impl(start, end, style, false, true);
+        self.annotations.push(Annotation {
+            start,
+            end,
+            text,
+            style,
+            replace: true,
+        });
+    }
+
+    fn push_impl(&mut self, start: usize, end: usize, style: StyleId, padded: bool, annotated: bool) {
         if start >= end {
             return;
         }
@@ -66,8 +152,11 @@ impl Collector {
         // Coalesce only into a span that shares both style and padding. A padded
         // badge must not merge with an adjacent same-style plain span, because
         // merge decides padding by whole-span extent — a merged span would
-        // either over- or under-pad.
+        // either over- or under-pad. An annotated span keeps its own range on
+        // either side.
         if let Some(last) = self.spans.last_mut()
+            && !annotated
+            && !last.annotated
             && last.style == style
             && last.padded == padded
             && last.end == start
@@ -81,25 +170,44 @@ impl Collector {
             style,
             priority: 0,
             padded,
+            annotated,
         });
     }
 
+    /// All collected spans, fallback spans included, in input order.
     #[cfg(test)]
-    pub(crate) fn into_spans(self) -> Vec<Span> {
+    pub(crate) fn into_spans(mut self) -> Vec<Span> {
+        self.spans.append(&mut self.fallback);
+        self.spans.sort_by_key(|span| span.start);
         self.spans
     }
 
+    /// All collected annotations, in push order.
+    #[cfg(test)]
+    pub(crate) fn into_annotations(self) -> Vec<Annotation> {
+        self.annotations
+    }
+
     pub fn reset(&mut self) {
         self.spans.clear();
+        self.fallback.clear();
+        self.annotations.clear();
     }
 
     /// Append this collector's spans to `spans`, stamping each with `priority`
-    /// (the producing finder's index). Leaves the collector empty for reuse.
-    pub fn drain_into(&mut self, spans: &mut Vec<Span>, priority: u16) {
+    /// (the producing finder's index) and each fallback span with `u16::MAX`,
+    /// and its annotations to `annotations`. Leaves the collector empty for
+    /// reuse.
+    pub fn drain_into(&mut self, spans: &mut Vec<Span>, annotations: &mut Vec<Annotation>, priority: u16) {
         for span in &mut self.spans {
             span.priority = priority;
         }
+        for span in &mut self.fallback {
+            span.priority = u16::MAX;
+        }
         spans.append(&mut self.spans);
+        spans.append(&mut self.fallback);
+        annotations.append(&mut self.annotations);
     }
 }
 
@@ -177,10 +285,55 @@ mod tests {
 
         let mut collector = Collector::new();
         collector.push_padded(0, 3, style);
+        collector.push_fallback(4, 6, style);
+        collector.push_annotated(7, 9, style, " note".to_string(), style);
         collector.reset();
         assert!(collector.into_spans().is_empty());
     }
 
+    #[test]
+    fn drain_stamps_fallback_spans_with_lowest_priority() {
+        let style = StyleId::new(0);
+        let mut collector = Collector::new();
+        collector.push_fallback(4, 6, style);
+        collector.push(0, 3, style);
+
+        let mut spans = Vec::new();
+        collector.drain_into(&mut spans, &mut Vec::new(), 2);
+        assert_eq!(spans[0].priority, 2);
+        assert_eq!(spans[1].priority, u16::MAX);
+        assert!(collector.into_spans().is_empty());
+    }
+
+    #[test]
+    fn drain_moves_annotations() {
+        let style = StyleId::new(0);
+        let mut collector = Collector::new();
+        collector.push_annotated(0, 3, style, " note".to_string(), style);
+
+        let mut spans = Vec::new();
+        let mut annotations = Vec::new();
+        collector.drain_into(&mut spans, &mut annotations, 0);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].text, " note");
+        assert!(collector.into_annotations().is_empty());
+    }
+
+    #[test]
+    fn does_not_coalesce_annotated_spans() {
+        let style = StyleId::new(0);
+        let mut collector = Collector::new();
+        collector.push(0, 7, style);
+        collector.push_replaced(7, 20, style, "…".to_string());
+        collector.push(20, 22, style);
+
+        let spans = collector.into_spans();
+        assert_eq!(spans.len(), 3);
+        assert_eq!((spans[1].start, spans[1].end), (7, 20));
+        assert!(spans[1].annotated);
+    }
+
     #[test]
     fn push_padded_marks_span_padded() {
         let style = StyleId::new(0);
@@ -22,7 +22,7 @@
 //!
 //! ```toml
 //! [dependencies]
-//! tailspin = { version = "7.0", default-features = false }
+//! tailspin = { version = "8.0", default-features = false }
 //! ```
 //!
 //!
@@ -65,9 +65,12 @@ pub use core::highlighter::{Error, Highlighter, HighlighterBuilder};
 /// Configuration support for custom highlighting themes and regex rules.
 pub mod config {
     pub use super::core::config::{
-        DateTimeConfig, DurationConfig, EmailConfig, IpV4Config, IpV6Config, JsonConfig, JvmStackTraceConfig,
-        KeyValueConfig, KeywordConfig, NumberConfig, PointerConfig, QuoteConfig, RegexConfig, UnixPathConfig,
-        UnixProcessConfig, UrlConfig, UuidConfig,
+        CloudResourceConfig, DateTimeConfig, DiffConfig, DurationConfig, EmailConfig, EpochConfig, GoPanicConfig,
+        HashConfig, HostnameConfig, IpV4Config, IpV6Config, JsDotnetStackTraceConfig, JsonConfig, JvmStackTraceConfig,
+        KeyValueConfig, KeywordConfig, MacAddressConfig, NumberConfig, PermissionConfig, PlaceholderConfig,
+        PointerConfig, PythonTracebackConfig, QuoteConfig, RegexConfig, RustBacktraceConfig, SizeConfig, SqlConfig,
+        SyslogConfig, TokenConfig, TraceIdConfig, UnixPathConfig, UnixProcessConfig, UrlConfig, UuidConfig,
+        WindowsPathConfig, XmlConfig,
     };
 }
 
@@ -12,19 +12,38 @@ pub struct Theme {
     pub regexes: Vec<RegexConfig>,
     pub numbers: NumberConfig,
     pub uuids: UuidConfig,
+    pub hashes: HashConfig,
     pub quotes: QuoteConfig,
     pub ipv4: IpV4Config,
     pub ipv6: IpV6Config,
+    pub mac_addresses: MacAddressConfig,
     pub dates: DateTimeConfig,
     pub durations: DurationConfig,
+    pub sizes: SizeConfig,
+    pub epochs: EpochConfig,
+    pub permissions: PermissionConfig,
     pub paths: UnixPathConfig,
     pub urls: UrlConfig,
     pub emails: EmailConfig,
+    pub hostnames: HostnameConfig,
+    pub cloud_resources: CloudResourceConfig,
+    pub placeholders: PlaceholderConfig,
+    pub tokens: TokenConfig,
+    pub trace_ids: TraceIdConfig,
     pub pointers: PointerConfig,
     pub processes: UnixProcessConfig,
+    pub syslog: SyslogConfig,
     pub key_value_pairs: KeyValueConfig,
     pub json: JsonConfig,
     pub jvm_stack_traces: JvmStackTraceConfig,
+    pub diff: DiffConfig,
+    pub sql: SqlConfig,
+    pub xml: XmlConfig,
+    pub windows_paths: WindowsPathConfig,
+    pub js_dotnet_stack_traces: JsDotnetStackTraceConfig,
+    pub rust_backtraces: RustBacktraceConfig,
+    pub go_panics: GoPanicConfig,
+    pub python_tracebacks: PythonTracebackConfig,
 }
 
 #[cfg(test)]